@@ -1,28 +1,25 @@
 use bitfinex_api::{
     api::{
         authenticated::{
-            account::balance_available::{BalanceAvailable, BalanceAvailableResp, BalanceType},
+            account::balance_available::{BalanceAvailable, BalanceType},
             funding::{
-                active_funding_offers::{ActiveFundingOffers, ActiveFundingOffersResp},
-                cancel_all_funding_offers::{CancelAllFundingOffers, CancelAllFundingOffersResp},
-                cancel_funding_offer::{CancelFundingOffer, CancelFundingOfferResp},
-                funding_credits::{FundingCredits, FundingCreditsResp},
-                funding_info::{FundingInfo, FundingInfoResp},
-                funding_loans::{FundingLoans, FundingLoansResp},
-                submit_funding_offer::{SubmitFundingOffer, SubmitFundingOfferResp},
-                types::FundingOfferType,
+                active_funding_offers::ActiveFundingOffers,
+                cancel_all_funding_offers::CancelAllFundingOffers,
+                cancel_funding_offer::CancelFundingOffer, funding_credits::FundingCredits,
+                funding_info::FundingInfo, funding_loans::FundingLoans,
+                submit_funding_offer::SubmitFundingOffer, types::FundingOfferType,
             },
             orders::{
-                cancel_order::{CancelOrder, CancelOrderResp},
-                cancel_orders::{CancelOrders, CancelOrdersResp, CancelOrdersType},
-                orders_history::{OrdersHistory, OrdersHistoryResp},
-                retrieve_orders::{RetrieveOrders, RetrieveOrdersResp},
-                retrieve_orders_by_symbol::{RetrieveOrdersBySymbol, RetrieveOrdersBySymbolResp},
-                submit_order::{SubmitOrder, SubmitOrderResp},
+                cancel_order::CancelOrder,
+                cancel_orders::{CancelOrders, CancelOrdersType},
+                orders_history::OrdersHistory,
+                retrieve_orders::RetrieveOrders,
+                retrieve_orders_by_symbol::RetrieveOrdersBySymbol,
+                submit_order::SubmitOrder,
                 types::{OrderFlag, OrderType},
             },
-            trades::{Trades, TradesResp},
-            wallets::{Wallets, WalletsResp},
+            trades::Trades,
+            wallets::Wallets,
         },
         ignore::ignore,
         query::AsyncQuery,
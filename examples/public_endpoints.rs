@@ -1,6 +1,6 @@
 use bitfinex_api::{
     api::{
-        common::{Section, Symbols, TimeFrame},
+        common::{Section, Symbol, Symbols, TimeFrame},
         public::{
             book::{
                 book::{Book, BookResp, Precision},
@@ -38,14 +38,14 @@ async fn main() {
     println!("{r:#?}");
 
     let endpoint = Tickers::builder()
-        .symbols(Symbols::Only(vec!["tBTCUSD"]))
+        .symbols(Symbols::only(["tBTCUSD"]))
         .build()
         .unwrap();
     let r: TickersResp = endpoint.query_async(&client).await.unwrap();
     println!("{r:#?}");
 
     let endpoint = TickersHistory::builder()
-        .symbols(Symbols::Only(vec!["tBTCUSD", "tARBF0:USTF0"]))
+        .symbols(Symbols::only(["tBTCUSD", "tARBF0:USTF0"]))
         .limit(1)
         .start(1694538014999)
         .end(1694538015000)
@@ -82,7 +82,7 @@ async fn main() {
 
     let endpoint = Stats::builder()
         .key_args(KeyArgs::PosSize {
-            sym: "tBTCUSD",
+            sym: Symbol::trading("BTC", "USD"),
             side: Side::Long,
         })
         .section(Section::Last)
@@ -103,7 +103,7 @@ async fn main() {
     let endpoint = Candles::builder()
         .candles(AvailableCandles::FundingCandles {
             time_frame: TimeFrame::FifteenMins,
-            currency: "fUSD",
+            currency: Symbol::funding("USD"),
             period: 120,
         })
         .section(Section::Last)
@@ -115,7 +115,7 @@ async fn main() {
     let endpoint = Candles::builder()
         .candles(AvailableCandles::AggregateFundingCandles {
             time_frame: TimeFrame::FifteenMins,
-            currency: "fUSD",
+            currency: Symbol::funding("USD"),
             aggregation: 30,
             period_start: 2,
             period_end: 30,
@@ -128,7 +128,7 @@ async fn main() {
     println!("{r:#?}");
 
     let endpoint = DerivativesStatus::builder()
-        .keys(Symbols::Only(vec!["tBTCF0:USTF0", "tETHF0:USTF0"]))
+        .keys(Symbols::only(["tBTCF0:USTF0", "tETHF0:USTF0"]))
         .build()
         .unwrap();
     let r: DerivativesStatusResp = endpoint.query_async(&client).await.unwrap();
@@ -0,0 +1,34 @@
+use bitfinex_api::{
+    api::{
+        common::{Symbol, TimeFrame},
+        public::{book::book::Precision, candles::AvailableCandles},
+    },
+    ws::{
+        client::BitfinexWs,
+        subscription::{Frequency, Subscription},
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    let mut ws = BitfinexWs::connect().await.unwrap();
+
+    ws.subscribe(&Subscription::ticker("tBTCUSD")).unwrap();
+    ws.subscribe(&Subscription::trades("tBTCUSD")).unwrap();
+    ws.subscribe(&Subscription::book(
+        "tBTCUSD",
+        Precision::P0,
+        Frequency::F0,
+        None,
+    ))
+    .unwrap();
+    ws.subscribe(&Subscription::candles(AvailableCandles::TradingCandles {
+        time_frame: TimeFrame::OneMin,
+        trading_pair: Symbol::trading("BTC", "USD"),
+    }))
+    .unwrap();
+
+    while let Some(event) = ws.next_event().await {
+        println!("{:#?}", event.unwrap());
+    }
+}
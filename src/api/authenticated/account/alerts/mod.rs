@@ -0,0 +1,92 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+
+use crate::api::endpoint::Endpoint;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertType {
+    Price,
+}
+
+/// https://docs.bitfinex.com/reference/rest-auth-alerts
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Alerts {
+    #[builder(default = "AlertType::Price")]
+    ty: AlertType,
+}
+
+impl Alerts {
+    pub fn builder() -> AlertsBuilder {
+        AlertsBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(rename(serialize = "type"))]
+            ty: AlertType,
+        }
+
+        let p = JsonParams { ty: self.ty };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for Alerts {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/alerts")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type AlertsResp = Vec<Alert>;
+
+#[derive(Debug)]
+pub struct Alert {
+    /// The key identifying the alert (e.g. `price:tBTCUSD:560.92`), used to delete it.
+    pub key: String,
+    pub ty: AlertType,
+    pub symbol: String,
+    pub price: f64,
+}
+
+impl<'de> Deserialize<'de> for Alert {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct AlertRaw(String, AlertType, String, f64, IgnoredAny);
+
+        impl From<AlertRaw> for Alert {
+            fn from(value: AlertRaw) -> Self {
+                let AlertRaw(key, ty, symbol, price, _) = value;
+
+                Self {
+                    key,
+                    ty,
+                    symbol,
+                    price,
+                }
+            }
+        }
+
+        let raw = AlertRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_repr::Serialize_repr;
 use serde_with::serde_as;
 
-use crate::api::endpoint::Endpoint;
+use crate::api::{common::Symbol, endpoint::Endpoint};
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -25,7 +25,8 @@ pub enum OrderDir {
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct BalanceAvailable<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     ty: BalanceType,
     #[builder(default)]
     dir: Option<OrderDir>,
@@ -44,7 +45,7 @@ impl<'a> BalanceAvailable<'a> {
         #[serde_as]
         #[derive(Debug, Serialize)]
         pub struct JsonParams<'a> {
-            symbol: &'a str,
+            symbol: Symbol<'a>,
             #[serde(rename(serialize = "type"))]
             ty: BalanceType,
             dir: Option<OrderDir>,
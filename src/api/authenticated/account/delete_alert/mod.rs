@@ -0,0 +1,51 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::Endpoint;
+
+/// https://docs.bitfinex.com/reference/rest-auth-alert-del
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeleteAlert<'a> {
+    /// The key of the alert, see `Alert::key`.
+    key: &'a str,
+}
+
+impl<'a> DeleteAlert<'a> {
+    pub fn builder() -> DeleteAlertBuilder<'a> {
+        DeleteAlertBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for DeleteAlert<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/auth/w/alert/{}/del", self.key)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug)]
+pub struct DeleteAlertResp {
+    pub success: bool,
+}
+
+impl<'de> Deserialize<'de> for DeleteAlertResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [success] = <[bool; 1]>::deserialize(deserializer)?;
+        Ok(Self { success })
+    }
+}
@@ -0,0 +1,90 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{common::Symbol, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-deriv-pos-collateral-set
+///
+/// `symbol` is a derivative key, as returned in `DerivativeStatusResp::key` (e.g. `tBTCF0:USTF0`).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DerivativeCollateral<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+    collateral: f64,
+}
+
+impl<'a> DerivativeCollateral<'a> {
+    pub fn builder() -> DerivativeCollateralBuilder<'a> {
+        DerivativeCollateralBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            symbol: Symbol<'a>,
+            #[serde_as(as = "serde_with::DisplayFromStr")]
+            collateral: f64,
+        }
+
+        let p = JsonParams {
+            symbol: self.symbol,
+            collateral: self.collateral,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for DerivativeCollateral<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/deriv/collateral/set")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    /// The collateral is set to an absolute value.
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct DerivativeCollateralResp {
+    pub success: bool,
+}
+
+impl<'de> Deserialize<'de> for DerivativeCollateralResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct DerivativeCollateralRawResp(u8);
+
+        impl From<DerivativeCollateralRawResp> for DerivativeCollateralResp {
+            fn from(value: DerivativeCollateralRawResp) -> Self {
+                let DerivativeCollateralRawResp(status) = value;
+
+                Self {
+                    success: status == 1,
+                }
+            }
+        }
+
+        let [[raw]] = <[[DerivativeCollateralRawResp; 1]; 1]>::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,82 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{common::Symbol, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-calc-deriv-collateral-limits
+///
+/// `symbol` is a derivative key, as returned in `DerivativeStatusResp::key` (e.g. `tBTCF0:USTF0`).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DerivativeCollateralLimits<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+}
+
+impl<'a> DerivativeCollateralLimits<'a> {
+    pub fn builder() -> DerivativeCollateralLimitsBuilder<'a> {
+        DerivativeCollateralLimitsBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            symbol: Symbol<'a>,
+        }
+
+        let p = JsonParams {
+            symbol: self.symbol,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for DerivativeCollateralLimits<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/calc/deriv/collateral/limits")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+/// The range the collateral of the position can be set to.
+#[derive(Debug)]
+pub struct DerivativeCollateralLimitsResp {
+    pub min_collateral: f64,
+    pub max_collateral: f64,
+}
+
+impl<'de> Deserialize<'de> for DerivativeCollateralLimitsResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct DerivativeCollateralLimitsRawResp(f64, f64);
+
+        impl From<DerivativeCollateralLimitsRawResp> for DerivativeCollateralLimitsResp {
+            fn from(value: DerivativeCollateralLimitsRawResp) -> Self {
+                let DerivativeCollateralLimitsRawResp(min_collateral, max_collateral) = value;
+
+                Self {
+                    min_collateral,
+                    max_collateral,
+                }
+            }
+        }
+
+        let raw = DerivativeCollateralLimitsRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
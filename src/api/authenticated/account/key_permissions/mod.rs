@@ -0,0 +1,64 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::endpoint::Endpoint;
+
+/// https://docs.bitfinex.com/reference/key-permissions
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct KeyPermissions {}
+
+impl KeyPermissions {
+    pub fn builder() -> KeyPermissionsBuilder {
+        KeyPermissionsBuilder::default()
+    }
+}
+
+impl Endpoint for KeyPermissions {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/permissions")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+pub type KeyPermissionsResp = Vec<KeyPermission>;
+
+/// The access granted to the API key on a scope (e.g. `orders`, `withdraw`).
+#[derive(Debug)]
+pub struct KeyPermission {
+    pub scope: String,
+    pub read: bool,
+    pub write: bool,
+}
+
+impl<'de> Deserialize<'de> for KeyPermission {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct KeyPermissionRaw(String, u8, u8);
+
+        impl From<KeyPermissionRaw> for KeyPermission {
+            fn from(value: KeyPermissionRaw) -> Self {
+                let KeyPermissionRaw(scope, read, write) = value;
+
+                Self {
+                    scope,
+                    read: read == 1,
+                    write: write == 1,
+                }
+            }
+        }
+
+        let raw = KeyPermissionRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
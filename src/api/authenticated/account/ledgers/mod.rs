@@ -0,0 +1,171 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_repr::Serialize_repr;
+
+use crate::api::{authenticated::wallets::WalletType, common::PlaceHolder, endpoint::Endpoint};
+
+/// The ledger entry categories, used to filter the entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr)]
+#[repr(u16)]
+pub enum LedgerCategory {
+    Exchange = 5,
+    PositionModified = 22,
+    PositionClaim = 23,
+    PositionTransfer = 25,
+    PositionSwap = 26,
+    PositionFundingCost = 27,
+    MarginFundingPayment = 28,
+    MarginFundingCharge = 29,
+    Settlement = 31,
+    Transfer = 51,
+    Deposit = 101,
+    Withdrawal = 104,
+    CanceledWithdrawal = 105,
+    TradingFee = 201,
+    TradingRebate = 202,
+    HiddenOrderFee = 204,
+    OtcTradeFee = 207,
+    SwapFee = 222,
+    ClaimingFee = 224,
+    UsedMarginFundingCharge = 226,
+    UnusedMarginFundingFee = 228,
+    EarnedFee = 241,
+    WithdrawalFee = 251,
+    DepositFee = 254,
+    WithdrawalExpressFee = 255,
+    MinerFee = 258,
+    StakingPayment = 401,
+    Adjustment = 501,
+}
+
+/// https://docs.bitfinex.com/reference/rest-auth-ledgers
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct Ledgers<'a> {
+    /// The currency of the entries (e.g. `USD`), all the currencies when not set.
+    #[builder(default)]
+    currency: Option<&'a str>,
+    #[builder(default)]
+    category: Option<LedgerCategory>,
+    #[builder(default)]
+    start: Option<u64>,
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl<'a> Ledgers<'a> {
+    pub fn builder() -> LedgersBuilder<'a> {
+        LedgersBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            category: Option<LedgerCategory>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+        }
+
+        let p = JsonParams {
+            category: self.category,
+            start: self.start,
+            end: self.end,
+            limit: self.limit,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for Ledgers<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        match self.currency {
+            Some(currency) => format!("v2/auth/r/ledgers/{currency}/hist"),
+            None => String::from("v2/auth/r/ledgers/hist"),
+        }
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type LedgersResp = Vec<LedgerEntry>;
+
+/// The wallet of a ledger entry, Bitfinex can return wallets this crate doesn't know about.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum LedgerWalletType {
+    Known(WalletType),
+    /// The wallet as sent by Bitfinex (e.g. `contribution`).
+    Unknown(String),
+}
+
+#[derive(Debug)]
+pub struct LedgerEntry {
+    pub id: u64,
+    pub currency: String,
+    pub wallet: Option<LedgerWalletType>,
+    pub mts: u64,
+    pub amount: f64,
+    /// The balance of the wallet after the entry.
+    pub balance: f64,
+    pub description: String,
+}
+
+impl<'de> Deserialize<'de> for LedgerEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct LedgerEntryRaw(
+            u64,
+            String,
+            Option<LedgerWalletType>,
+            u64,
+            PlaceHolder,
+            f64,
+            f64,
+            PlaceHolder,
+            String,
+        );
+
+        impl From<LedgerEntryRaw> for LedgerEntry {
+            fn from(value: LedgerEntryRaw) -> Self {
+                let LedgerEntryRaw(id, currency, wallet, mts, _, amount, balance, _, description) =
+                    value;
+
+                Self {
+                    id,
+                    currency,
+                    wallet,
+                    mts,
+                    amount,
+                    balance,
+                    description,
+                }
+            }
+        }
+
+        let raw = LedgerEntryRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,113 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-logins-hist
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct LoginHistory {
+    #[builder(default)]
+    start: Option<u64>,
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl LoginHistory {
+    pub fn builder() -> LoginHistoryBuilder {
+        LoginHistoryBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+        }
+
+        let p = JsonParams {
+            start: self.start,
+            end: self.end,
+            limit: self.limit,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for LoginHistory {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/logins/hist")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type LoginHistoryResp = Vec<Login>;
+
+/// [0] ID          int     Login id
+/// [2] TIME        int     Login timestamp
+/// [4] IP          string  IP address of the login
+/// [7] EXTRA_INFO  object  Additional details (e.g. the user agent)
+///
+#[derive(Debug)]
+pub struct Login {
+    pub id: u64,
+    pub time: u64,
+    pub ip: String,
+    pub extra_info: Option<Value>,
+}
+
+impl<'de> Deserialize<'de> for Login {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct LoginRaw(
+            u64,
+            PlaceHolder,
+            u64,
+            PlaceHolder,
+            String,
+            PlaceHolder,
+            PlaceHolder,
+            Option<Value>,
+        );
+
+        impl From<LoginRaw> for Login {
+            fn from(value: LoginRaw) -> Self {
+                let LoginRaw(id, _, time, _, ip, _, _, extra_info) = value;
+
+                Self {
+                    id,
+                    time,
+                    ip,
+                    extra_info,
+                }
+            }
+        }
+
+        let raw = LoginRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,169 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{common::Symbol, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-info-margin
+///
+/// The margin info of the account (`base` key).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct BaseMarginInfo {}
+
+impl BaseMarginInfo {
+    pub fn builder() -> BaseMarginInfoBuilder {
+        BaseMarginInfoBuilder::default()
+    }
+}
+
+impl Endpoint for BaseMarginInfo {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/info/margin/base")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+/// https://docs.bitfinex.com/reference/rest-auth-info-margin
+///
+/// The margin info of a trading pair (e.g. `tBTCUSD`) or derivative key (e.g. `tBTCF0:USTF0`).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct SymbolMarginInfo<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+}
+
+impl<'a> SymbolMarginInfo<'a> {
+    pub fn builder() -> SymbolMarginInfoBuilder<'a> {
+        SymbolMarginInfoBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for SymbolMarginInfo<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/auth/r/info/margin/{}", self.symbol)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+/// https://docs.bitfinex.com/reference/rest-auth-info-margin
+///
+/// The margin info of all the trading pairs (`sym_all` key).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct AllSymbolsMarginInfo {}
+
+impl AllSymbolsMarginInfo {
+    pub fn builder() -> AllSymbolsMarginInfoBuilder {
+        AllSymbolsMarginInfoBuilder::default()
+    }
+}
+
+impl Endpoint for AllSymbolsMarginInfo {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/info/margin/sym_all")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug)]
+pub struct BaseMarginInfoResp {
+    pub user_pl: f64,
+    pub user_swaps: f64,
+    pub margin_balance: f64,
+    pub margin_net: f64,
+    pub margin_min: f64,
+}
+
+impl<'de> Deserialize<'de> for BaseMarginInfoResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct BaseMarginInfoRawResp(String, (f64, f64, f64, f64, f64));
+
+        impl From<BaseMarginInfoRawResp> for BaseMarginInfoResp {
+            fn from(value: BaseMarginInfoRawResp) -> Self {
+                let BaseMarginInfoRawResp(
+                    _,
+                    (user_pl, user_swaps, margin_balance, margin_net, margin_min),
+                ) = value;
+
+                Self {
+                    user_pl,
+                    user_swaps,
+                    margin_balance,
+                    margin_net,
+                    margin_min,
+                }
+            }
+        }
+
+        let raw = BaseMarginInfoRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+#[derive(Debug)]
+pub struct SymbolMarginInfoResp {
+    pub symbol: String,
+    pub tradable_balance: f64,
+    pub gross_balance: f64,
+    pub buy: f64,
+    pub sell: f64,
+}
+
+impl<'de> Deserialize<'de> for SymbolMarginInfoResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct SymbolMarginInfoRawResp(String, String, (f64, f64, f64, f64));
+
+        impl From<SymbolMarginInfoRawResp> for SymbolMarginInfoResp {
+            fn from(value: SymbolMarginInfoRawResp) -> Self {
+                let SymbolMarginInfoRawResp(
+                    _,
+                    symbol,
+                    (tradable_balance, gross_balance, buy, sell),
+                ) = value;
+
+                Self {
+                    symbol,
+                    tradable_balance,
+                    gross_balance,
+                    buy,
+                    sell,
+                }
+            }
+        }
+
+        let raw = SymbolMarginInfoRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+pub type AllSymbolsMarginInfoResp = Vec<SymbolMarginInfoResp>;
@@ -1 +1,15 @@
+pub mod alerts;
 pub mod balance_available;
+pub mod delete_alert;
+pub mod delete_settings;
+pub mod deriv_collateral;
+pub mod deriv_collateral_limits;
+pub mod key_permissions;
+pub mod ledgers;
+pub mod login_history;
+pub mod margin_info;
+pub mod set_alert;
+pub mod set_settings;
+pub mod settings;
+pub mod summary;
+pub mod user_info;
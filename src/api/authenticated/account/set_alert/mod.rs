@@ -0,0 +1,69 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+use serde_with::serde_as;
+
+use crate::api::{common::Symbol, endpoint::Endpoint};
+
+use super::alerts::{Alert, AlertType};
+
+/// https://docs.bitfinex.com/reference/rest-auth-alert-set
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct SetAlert<'a> {
+    #[builder(default = "AlertType::Price")]
+    ty: AlertType,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+    price: f64,
+}
+
+impl<'a> SetAlert<'a> {
+    pub fn builder() -> SetAlertBuilder<'a> {
+        SetAlertBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            #[serde(rename(serialize = "type"))]
+            ty: AlertType,
+            symbol: Symbol<'a>,
+            #[serde_as(as = "serde_with::DisplayFromStr")]
+            price: f64,
+        }
+
+        let p = JsonParams {
+            ty: self.ty,
+            symbol: self.symbol,
+            price: self.price,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for SetAlert<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/alert/set")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    /// Alerts are keyed by symbol and price, setting one twice is a no-op.
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type SetAlertResp = Alert;
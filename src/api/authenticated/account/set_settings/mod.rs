@@ -0,0 +1,76 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::endpoint::Endpoint;
+
+/// https://docs.bitfinex.com/reference/rest-auth-settings-set
+///
+/// The keys must be prefixed with `api:` (e.g. `api:bot-config`), values can be any JSON value.
+#[derive(Debug, Clone, Builder)]
+pub struct SetSettings<'a> {
+    settings: Vec<(&'a str, Value)>,
+}
+
+impl<'a> SetSettings<'a> {
+    pub fn builder() -> SetSettingsBuilder<'a> {
+        SetSettingsBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            settings: Map<String, Value>,
+        }
+
+        let p = JsonParams {
+            settings: self
+                .settings
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.clone()))
+                .collect(),
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for SetSettings<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/settings/set")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct SetSettingsResp {
+    pub success: bool,
+}
+
+impl<'de> Deserialize<'de> for SetSettingsResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [status] = <[u8; 1]>::deserialize(deserializer)?;
+        Ok(Self {
+            success: status == 1,
+        })
+    }
+}
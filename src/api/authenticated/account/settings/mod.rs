@@ -0,0 +1,78 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::endpoint::Endpoint;
+
+/// https://docs.bitfinex.com/reference/rest-auth-settings
+///
+/// The keys of the settings written through the API are prefixed with `api:` (e.g. `api:bot-config`).
+#[derive(Debug, Clone, Builder)]
+pub struct Settings<'a> {
+    keys: Vec<&'a str>,
+}
+
+impl<'a> Settings<'a> {
+    pub fn builder() -> SettingsBuilder<'a> {
+        SettingsBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            keys: &'a [&'a str],
+        }
+
+        let p = JsonParams { keys: &self.keys };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for Settings<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/settings")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type SettingsResp = Vec<Setting>;
+
+#[derive(Debug)]
+pub struct Setting {
+    pub key: String,
+    pub value: Value,
+}
+
+impl<'de> Deserialize<'de> for Setting {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct SettingRaw(String, Value);
+
+        impl From<SettingRaw> for Setting {
+            fn from(value: SettingRaw) -> Self {
+                let SettingRaw(key, value) = value;
+
+                Self { key, value }
+            }
+        }
+
+        let raw = SettingRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use derive_builder::Builder;
+use http::Method;
+use serde::{de::IgnoredAny, Deserialize};
+use serde_with::serde_as;
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-summary
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct Summary {}
+
+impl Summary {
+    pub fn builder() -> SummaryBuilder {
+        SummaryBuilder::default()
+    }
+}
+
+impl Endpoint for Summary {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/summary")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+/// The fee rates of the account and its 30 days trading and funding activity.
+///
+/// The fees are ratios (e.g. `0.001` for 0.1%), negative fees are rebates.
+#[derive(Debug)]
+pub struct SummaryResp {
+    pub maker_fee: f64,
+    pub deriv_rebate: f64,
+    pub taker_fee_to_crypto: f64,
+    pub taker_fee_to_stable: f64,
+    pub taker_fee_to_fiat: f64,
+    pub deriv_taker_fee: f64,
+    /// The 30 days trading volume per currency, with a `Total (USD)` entry.
+    pub trade_volume: Vec<TradeVolume>,
+    /// The 30 days trading fees paid per currency.
+    pub trading_fees: HashMap<String, f64>,
+    /// The 30 days trading fees paid, in USD.
+    pub trading_fees_total: f64,
+    /// The 30 days funding fees paid per currency.
+    pub funding_fees: HashMap<String, f64>,
+    /// The 30 days funding fees paid, in USD.
+    pub funding_fees_total: f64,
+    /// The LEO level of the account, which determines the fee discounts.
+    pub leo_lev: u8,
+    /// The average LEO amount held in the last 30 days.
+    pub leo_amount_avg: f64,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize)]
+pub struct TradeVolume {
+    pub curr: String,
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    pub vol: f64,
+    #[serde(default)]
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    pub vol_safe: f64,
+    #[serde(default)]
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    pub vol_maker: f64,
+    #[serde(default, rename = "vol_BFX")]
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    pub vol_bfx: f64,
+    #[serde(default, rename = "vol_BFX_safe")]
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    pub vol_bfx_safe: f64,
+    #[serde(default, rename = "vol_BFX_maker")]
+    #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")]
+    pub vol_bfx_maker: f64,
+}
+
+impl<'de> Deserialize<'de> for SummaryResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct FeesRaw(f64, f64, f64, PlaceHolder, PlaceHolder, f64);
+
+        #[derive(Debug, Deserialize)]
+        struct FeeInfoRaw(FeesRaw, FeesRaw);
+
+        #[derive(Debug, Deserialize)]
+        struct TradingRaw(Vec<TradeVolume>, HashMap<String, f64>, f64);
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct FundingRaw(IgnoredAny, HashMap<String, f64>, f64);
+
+        #[derive(Debug, Default, Deserialize)]
+        struct LeoInfoRaw {
+            #[serde(default)]
+            leo_lev: u8,
+            #[serde(default)]
+            leo_amount_avg: f64,
+        }
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct SummaryRawResp(
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            FeeInfoRaw,
+            TradingRaw,
+            FundingRaw,
+            PlaceHolder,
+            PlaceHolder,
+            Option<LeoInfoRaw>,
+        );
+
+        impl From<SummaryRawResp> for SummaryResp {
+            fn from(value: SummaryRawResp) -> Self {
+                let SummaryRawResp(
+                    _,
+                    _,
+                    _,
+                    _,
+                    FeeInfoRaw(
+                        FeesRaw(maker_fee, _, _, _, _, deriv_rebate),
+                        FeesRaw(
+                            taker_fee_to_crypto,
+                            taker_fee_to_stable,
+                            taker_fee_to_fiat,
+                            _,
+                            _,
+                            deriv_taker_fee,
+                        ),
+                    ),
+                    TradingRaw(trade_volume, trading_fees, trading_fees_total),
+                    FundingRaw(_, funding_fees, funding_fees_total),
+                    _,
+                    _,
+                    leo_info,
+                ) = value;
+                let leo_info = leo_info.unwrap_or_default();
+
+                Self {
+                    maker_fee,
+                    deriv_rebate,
+                    taker_fee_to_crypto,
+                    taker_fee_to_stable,
+                    taker_fee_to_fiat,
+                    deriv_taker_fee,
+                    trade_volume,
+                    trading_fees,
+                    trading_fees_total,
+                    funding_fees,
+                    funding_fees_total,
+                    leo_lev: leo_info.leo_lev,
+                    leo_amount_avg: leo_info.leo_amount_avg,
+                }
+            }
+        }
+
+        let raw = SummaryRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,246 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{de, Deserialize};
+use serde_json::Value;
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-info-user
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct UserInfo {}
+
+impl UserInfo {
+    pub fn builder() -> UserInfoBuilder {
+        UserInfoBuilder::default()
+    }
+}
+
+impl Endpoint for UserInfo {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/info/user")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug)]
+pub struct UserInfoResp {
+    pub id: u64,
+    pub email: String,
+    pub username: String,
+    pub mts_account_create: u64,
+    /// Whether the account has been verified.
+    pub verified: bool,
+    pub verification_level: u8,
+    pub timezone: Option<String>,
+    pub locale: Option<String>,
+    pub company: Option<String>,
+    pub email_verified: bool,
+    pub subaccount_type: Option<String>,
+    pub mts_master_account_create: Option<u64>,
+    pub group_id: Option<u64>,
+    pub master_account_id: Option<u64>,
+    pub inherit_master_account_verification: bool,
+    pub is_group_master: bool,
+    pub group_withdraw_enabled: bool,
+    pub ppt_enabled: bool,
+    pub merchant_enabled: bool,
+    pub competition_enabled: bool,
+    /// The enabled 2FA modes (e.g. `u2f`, `otp`).
+    pub two_fa_modes: Vec<String>,
+    pub is_securities_master: bool,
+    pub securities_enabled: bool,
+    pub is_securities_investor_accredited: bool,
+    pub is_securities_el_salvador: bool,
+    pub allow_disable_ctxswitch: bool,
+    pub ctxswitch_disabled: bool,
+    pub time_last_login: Option<u64>,
+    pub verification_level_submitted: Option<u8>,
+    /// The countries the account is compliant with.
+    pub comp_countries: Vec<String>,
+    pub comp_countries_resid: Vec<String>,
+    pub compl_account_type: Option<String>,
+    pub is_merchant_enterprise: bool,
+}
+
+impl<'de> Deserialize<'de> for UserInfoResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // The number of fields documented for the response, Bitfinex appends new fields at the
+        // end of the array so the ones after these are ignored.
+        const FIELDS: usize = 55;
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct UserInfoRawResp(
+            u64,
+            String,
+            String,
+            u64,
+            u8,
+            u8,
+            PlaceHolder,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            u8,
+            PlaceHolder,
+            Option<String>,
+            PlaceHolder,
+            Option<u64>,
+            Option<u64>,
+            Option<u64>,
+            Option<u8>,
+            Option<u8>,
+            Option<u8>,
+            PlaceHolder,
+            Option<u8>,
+            Option<u8>,
+            Option<u8>,
+            PlaceHolder,
+            PlaceHolder,
+            Option<Vec<String>>,
+            PlaceHolder,
+            Option<u8>,
+            Option<u8>,
+            Option<u8>,
+            Option<u8>,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            Option<u8>,
+            Option<u8>,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            Option<u64>,
+            PlaceHolder,
+            PlaceHolder,
+            Option<u8>,
+            PlaceHolder,
+            Option<Vec<String>>,
+            Option<Vec<String>>,
+            Option<String>,
+            PlaceHolder,
+            PlaceHolder,
+            Option<u8>,
+        );
+
+        impl From<UserInfoRawResp> for UserInfoResp {
+            fn from(value: UserInfoRawResp) -> Self {
+                let UserInfoRawResp(
+                    id,
+                    email,
+                    username,
+                    mts_account_create,
+                    verified,
+                    verification_level,
+                    _,
+                    timezone,
+                    locale,
+                    company,
+                    email_verified,
+                    _,
+                    subaccount_type,
+                    _,
+                    mts_master_account_create,
+                    group_id,
+                    master_account_id,
+                    inherit_master_account_verification,
+                    is_group_master,
+                    group_withdraw_enabled,
+                    _,
+                    ppt_enabled,
+                    merchant_enabled,
+                    competition_enabled,
+                    _,
+                    _,
+                    two_fa_modes,
+                    _,
+                    is_securities_master,
+                    securities_enabled,
+                    is_securities_investor_accredited,
+                    is_securities_el_salvador,
+                    _,
+                    _,
+                    _,
+                    _,
+                    _,
+                    _,
+                    allow_disable_ctxswitch,
+                    ctxswitch_disabled,
+                    _,
+                    _,
+                    _,
+                    _,
+                    time_last_login,
+                    _,
+                    _,
+                    verification_level_submitted,
+                    _,
+                    comp_countries,
+                    comp_countries_resid,
+                    compl_account_type,
+                    _,
+                    _,
+                    is_merchant_enterprise,
+                ) = value;
+
+                Self {
+                    id,
+                    email,
+                    username,
+                    mts_account_create,
+                    verified: verified == 1,
+                    verification_level,
+                    timezone,
+                    locale,
+                    company,
+                    email_verified: email_verified == 1,
+                    subaccount_type,
+                    mts_master_account_create,
+                    group_id,
+                    master_account_id,
+                    inherit_master_account_verification: inherit_master_account_verification
+                        == Some(1),
+                    is_group_master: is_group_master == Some(1),
+                    group_withdraw_enabled: group_withdraw_enabled == Some(1),
+                    ppt_enabled: ppt_enabled == Some(1),
+                    merchant_enabled: merchant_enabled == Some(1),
+                    competition_enabled: competition_enabled == Some(1),
+                    two_fa_modes: two_fa_modes.unwrap_or_default(),
+                    is_securities_master: is_securities_master == Some(1),
+                    securities_enabled: securities_enabled == Some(1),
+                    is_securities_investor_accredited: is_securities_investor_accredited == Some(1),
+                    is_securities_el_salvador: is_securities_el_salvador == Some(1),
+                    allow_disable_ctxswitch: allow_disable_ctxswitch == Some(1),
+                    ctxswitch_disabled: ctxswitch_disabled == Some(1),
+                    time_last_login,
+                    verification_level_submitted,
+                    comp_countries: comp_countries.unwrap_or_default(),
+                    comp_countries_resid: comp_countries_resid.unwrap_or_default(),
+                    compl_account_type,
+                    is_merchant_enterprise: is_merchant_enterprise == Some(1),
+                }
+            }
+        }
+
+        let mut fields = Vec::<Value>::deserialize(deserializer)?;
+        fields.truncate(FIELDS);
+
+        let raw = UserInfoRawResp::deserialize(Value::Array(fields)).map_err(de::Error::custom)?;
+        Ok(raw.into())
+    }
+}
@@ -1,15 +1,15 @@
 use derive_builder::Builder;
 use http::Method;
 
-use crate::api::endpoint::Endpoint;
+use crate::api::{common::Symbol, endpoint::Endpoint};
 
 use super::types::FundingOffer;
 
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct ActiveFundingOffers<'a> {
-    #[builder(default)]
-    symbol: Option<&'a str>,
+    #[builder(default, setter(into))]
+    symbol: Option<Symbol<'a>>,
 }
 
 impl<'a> ActiveFundingOffers<'a> {
@@ -43,6 +43,10 @@ impl<'a> Endpoint for CancelAllFundingOffers<'a> {
         true
     }
 
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
     fn body(&self) -> Option<(&'static str, Vec<u8>)> {
         Some(("application/json", self.json_body().into_bytes()))
     }
@@ -61,6 +65,7 @@ impl<'de> Deserialize<'de> for CancelAllFundingOffersResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct CancelAllFundingOffersRawResp(
             u64,
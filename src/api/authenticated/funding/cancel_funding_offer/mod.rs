@@ -2,7 +2,7 @@ use derive_builder::Builder;
 use http::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::api::endpoint::Endpoint;
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
 
 use super::types::{FundingOffer, FundingOfferRaw};
 
@@ -41,6 +41,10 @@ impl Endpoint for CancelFundingOffer {
         true
     }
 
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
     fn body(&self) -> Option<(&'static str, Vec<u8>)> {
         Some(("application/json", self.json_body().into_bytes()))
     }
@@ -50,9 +54,9 @@ impl Endpoint for CancelFundingOffer {
 pub struct CancelFundingOfferResp {
     pub mts: u64,
     pub ty: String,
-    pub message_id: u64,
+    pub message_id: Option<u64>,
     pub offer: FundingOffer,
-    pub code: u64,
+    pub code: Option<u64>,
     pub status: String,
     pub text: String,
 }
@@ -62,12 +66,22 @@ impl<'de> Deserialize<'de> for CancelFundingOfferResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
-        struct CancelFundingOfferRawResp(u64, String, u64, FundingOfferRaw, u64, String, String);
+        struct CancelFundingOfferRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            FundingOfferRaw,
+            Option<u64>,
+            String,
+            String,
+        );
 
         impl From<CancelFundingOfferRawResp> for CancelFundingOfferResp {
             fn from(value: CancelFundingOfferRawResp) -> Self {
-                let CancelFundingOfferRawResp(mts, ty, message_id, offer, code, status, text) =
+                let CancelFundingOfferRawResp(mts, ty, message_id, _, offer, code, status, text) =
                     value;
 
                 Self {
@@ -0,0 +1,106 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::LoanOrCredit;
+
+/// https://docs.bitfinex.com/reference/rest-auth-funding-close
+///
+/// Returns a taken loan or credit before the end of its period.
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct CloseFunding {
+    id: u64,
+}
+
+impl CloseFunding {
+    pub fn builder() -> CloseFundingBuilder {
+        CloseFundingBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            id: u64,
+        }
+
+        let p = JsonParams { id: self.id };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for CloseFunding {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/funding/close")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+/// `funding` is `None` when Bitfinex sends the notification without data.
+#[derive(Debug)]
+pub struct CloseFundingResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub funding: Option<LoanOrCredit>,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for CloseFundingResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct CloseFundingRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            Option<LoanOrCredit>,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<CloseFundingRawResp> for CloseFundingResp {
+            fn from(value: CloseFundingRawResp) -> Self {
+                let CloseFundingRawResp(mts, ty, message_id, _, funding, code, status, text) =
+                    value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    funding,
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = CloseFundingRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,149 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-funding-auto-renew
+///
+/// The `rate` is a percentage (e.g. `0.8` for 0.8%), the FRR is used when it isn't set.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct FundingAutoRenew<'a> {
+    currency: &'a str,
+    active: bool,
+    #[builder(default)]
+    amount: Option<f64>,
+    #[builder(default)]
+    rate: Option<f64>,
+    #[builder(default)]
+    period: Option<u8>,
+}
+
+impl<'a> FundingAutoRenew<'a> {
+    pub fn builder() -> FundingAutoRenewBuilder<'a> {
+        FundingAutoRenewBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            status: u8,
+            currency: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            amount: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            rate: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            period: Option<u8>,
+        }
+
+        let p = JsonParams {
+            status: self.active as u8,
+            currency: self.currency,
+            amount: self.amount,
+            rate: self.rate,
+            period: self.period,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for FundingAutoRenew<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/funding/auto")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct FundingAutoRenewResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    /// The auto-renew settings, `None` when it has been disabled.
+    pub settings: Option<AutoRenewSettings>,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct AutoRenewSettings {
+    pub currency: String,
+    pub period: u8,
+    pub rate: f64,
+    pub threshold: f64,
+}
+
+impl<'de> Deserialize<'de> for FundingAutoRenewResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct AutoRenewSettingsRaw(String, u8, f64, f64);
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct FundingAutoRenewRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            Option<AutoRenewSettingsRaw>,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<FundingAutoRenewRawResp> for FundingAutoRenewResp {
+            fn from(value: FundingAutoRenewRawResp) -> Self {
+                let FundingAutoRenewRawResp(mts, ty, message_id, _, settings, code, status, text) =
+                    value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    settings: settings.map(
+                        |AutoRenewSettingsRaw(currency, period, rate, threshold)| {
+                            AutoRenewSettings {
+                                currency,
+                                period,
+                                rate,
+                                threshold,
+                            }
+                        },
+                    ),
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = FundingAutoRenewRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,72 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+
+use crate::api::{common::Symbol, endpoint::Endpoint};
+
+use super::funding_credits::FundingCreditResp;
+
+/// https://docs.bitfinex.com/reference/rest-auth-funding-credits-hist
+///
+/// The history of all the currencies is returned when `symbol` isn't set.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct FundingCreditsHistory<'a> {
+    #[builder(default, setter(into))]
+    symbol: Option<Symbol<'a>>,
+    #[builder(default)]
+    start: Option<u64>,
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl<'a> FundingCreditsHistory<'a> {
+    pub fn builder() -> FundingCreditsHistoryBuilder<'a> {
+        FundingCreditsHistoryBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+        }
+
+        let p = JsonParams {
+            start: self.start,
+            end: self.end,
+            limit: self.limit,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for FundingCreditsHistory<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        match self.symbol {
+            Some(symbol) => format!("v2/auth/r/funding/credits/{symbol}/hist"),
+            None => String::from("v2/auth/r/funding/credits/hist"),
+        }
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type FundingCreditsHistoryResp = Vec<FundingCreditResp>;
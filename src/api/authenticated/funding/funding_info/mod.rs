@@ -2,11 +2,12 @@ use derive_builder::Builder;
 use http::Method;
 use serde::Deserialize;
 
-use crate::api::endpoint::Endpoint;
+use crate::api::{common::Symbol, endpoint::Endpoint};
 
 #[derive(Debug, Clone, Copy, Builder)]
 pub struct FundingInfo<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
 }
 
 impl<'a> FundingInfo<'a> {
@@ -43,6 +44,7 @@ impl<'de> Deserialize<'de> for FundingInfoResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         pub struct FundingInfoRespRaw(String, String, (f64, f64, f64, f64));
 
@@ -3,13 +3,17 @@ use http::Method;
 use serde::Deserialize;
 use serde_json::Value;
 
-use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
 
 use super::types::{LoanSide, RateType};
 
 #[derive(Debug, Clone, Copy, Builder)]
 pub struct FundingLoans<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
 }
 
 impl<'a> FundingLoans<'a> {
@@ -59,6 +63,7 @@ impl<'de> Deserialize<'de> for FundingLoanResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         pub struct FundingLoanRespRaw(
             u64,
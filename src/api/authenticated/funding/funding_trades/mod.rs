@@ -0,0 +1,123 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
+
+/// https://docs.bitfinex.com/reference/rest-auth-funding-trades-hist
+///
+/// The history of all the currencies is returned when `symbol` isn't set.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct FundingTrades<'a> {
+    #[builder(default, setter(into))]
+    symbol: Option<Symbol<'a>>,
+    #[builder(default)]
+    start: Option<u64>,
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl<'a> FundingTrades<'a> {
+    pub fn builder() -> FundingTradesBuilder<'a> {
+        FundingTradesBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+        }
+
+        let p = JsonParams {
+            start: self.start,
+            end: self.end,
+            limit: self.limit,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for FundingTrades<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        match self.symbol {
+            Some(symbol) => format!("v2/auth/r/funding/trades/{symbol}/hist"),
+            None => String::from("v2/auth/r/funding/trades/hist"),
+        }
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type FundingTradesResp = Vec<FundingTrade>;
+
+/// [0] ID          int     Funding trade id
+/// [1] CURRENCY    string  Currency (fUSD, …)
+/// [2] MTS_CREATE  int     Execution timestamp
+/// [3] OFFER_ID    int     Funding offer id
+/// [4] AMOUNT      float   Positive means lent, negative means borrowed
+/// [5] RATE        float   Daily rate
+/// [6] PERIOD      int     Number of days of the funding
+///
+#[derive(Debug)]
+pub struct FundingTrade {
+    pub id: u64,
+    pub currency: String,
+    pub mts_create: u64,
+    pub offer_id: u64,
+    pub amount: f64,
+    pub rate: f64,
+    pub period: u8,
+}
+
+impl<'de> Deserialize<'de> for FundingTrade {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct FundingTradeRaw(u64, String, u64, u64, f64, f64, u8, PlaceHolder);
+
+        impl From<FundingTradeRaw> for FundingTrade {
+            fn from(value: FundingTradeRaw) -> Self {
+                let FundingTradeRaw(id, currency, mts_create, offer_id, amount, rate, period, _) =
+                    value;
+
+                Self {
+                    id,
+                    currency,
+                    mts_create,
+                    offer_id,
+                    amount,
+                    rate,
+                    period,
+                }
+            }
+        }
+
+        let raw = FundingTradeRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
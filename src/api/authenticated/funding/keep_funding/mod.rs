@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::{FundingKind, LoanOrCredit};
+
+/// https://docs.bitfinex.com/reference/rest-auth-keep-funding
+///
+/// `changes` toggles the keep flag of several loans at once, keyed by loan id.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct KeepFunding {
+    ty: FundingKind,
+    id: u64,
+    #[builder(default)]
+    changes: Option<Vec<(u64, bool)>>,
+}
+
+impl KeepFunding {
+    pub fn builder() -> KeepFundingBuilder {
+        KeepFundingBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(rename(serialize = "type"))]
+            ty: FundingKind,
+            id: u64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            changes: Option<BTreeMap<u64, u8>>,
+        }
+
+        let p = JsonParams {
+            ty: self.ty,
+            id: self.id,
+            changes: self.changes.as_ref().map(|changes| {
+                changes
+                    .iter()
+                    .map(|(id, keep)| (*id, *keep as u8))
+                    .collect()
+            }),
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for KeepFunding {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/funding/keep")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+/// `funding` is `None` when Bitfinex sends the notification without data.
+#[derive(Debug)]
+pub struct KeepFundingResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub funding: Option<LoanOrCredit>,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for KeepFundingResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct KeepFundingRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            Option<LoanOrCredit>,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<KeepFundingRawResp> for KeepFundingResp {
+            fn from(value: KeepFundingRawResp) -> Self {
+                let KeepFundingRawResp(mts, ty, message_id, _, funding, code, status, text) = value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    funding,
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = KeepFundingRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -1,8 +1,15 @@
 pub mod active_funding_offers;
 pub mod cancel_all_funding_offers;
 pub mod cancel_funding_offer;
+pub mod close_funding;
+pub mod funding_auto_renew;
 pub mod funding_credits;
+pub mod funding_credits_history;
 pub mod funding_info;
 pub mod funding_loans;
+pub mod funding_loans_history;
+pub mod funding_offers_history;
+pub mod funding_trades;
+pub mod keep_funding;
 pub mod submit_funding_offer;
 pub mod types;
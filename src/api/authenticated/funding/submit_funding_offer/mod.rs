@@ -3,7 +3,10 @@ use http::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
 
 use super::types::{FundingOffer, FundingOfferRaw, FundingOfferType};
 
@@ -11,7 +14,8 @@ use super::types::{FundingOffer, FundingOfferRaw, FundingOfferType};
 #[builder(setter(strip_option))]
 pub struct SubmitFundingOffer<'a> {
     ty: FundingOfferType,
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     amount: f64,
     rate: f64,
     period: u8,
@@ -30,7 +34,7 @@ impl<'a> SubmitFundingOffer<'a> {
         pub struct JsonParams<'a> {
             #[serde(rename(serialize = "type"))]
             ty: FundingOfferType,
-            symbol: &'a str,
+            symbol: Symbol<'a>,
             #[serde_as(as = "serde_with::DisplayFromStr")]
             amount: f64,
             #[serde_as(as = "serde_with::DisplayFromStr")]
@@ -86,6 +90,7 @@ impl<'de> Deserialize<'de> for SubmitFundingOfferResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct SubmitFundingOfferRawResp(
             u64,
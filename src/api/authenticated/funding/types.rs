@@ -3,6 +3,8 @@ use serde_repr::Deserialize_repr;
 
 use crate::api::common::PlaceHolder;
 
+use super::{funding_credits::FundingCreditResp, funding_loans::FundingLoanResp};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum RateType {
@@ -61,6 +63,7 @@ enum BoolOrU8 {
     U8(u8),
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct FundingOfferRaw(
     u64,
@@ -139,3 +142,19 @@ impl From<FundingOfferRaw> for FundingOffer {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FundingKind {
+    Loan,
+    Credit,
+}
+
+/// A loan or a credit sent back by a funding notification, a credit has a trailing
+/// `POSITION_PAIR` field.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LoanOrCredit {
+    Loan(FundingLoanResp),
+    Credit(FundingCreditResp),
+}
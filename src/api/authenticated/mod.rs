@@ -1,5 +1,9 @@
 pub mod account;
 pub mod funding;
+pub mod movements;
 pub mod orders;
+pub mod positions;
+pub mod pulse;
+pub mod transfer;
 pub mod wallets;
 pub mod trades;
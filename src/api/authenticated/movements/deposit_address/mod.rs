@@ -0,0 +1,114 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{authenticated::wallets::WalletType, common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::{DepositAddress, DepositAddressRaw, MovementMethod};
+
+/// https://docs.bitfinex.com/reference/rest-auth-deposit-address
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct GetDepositAddress<'a> {
+    wallet: WalletType,
+    method: MovementMethod<'a>,
+    /// Generates a new address instead of returning the current one.
+    #[builder(default)]
+    op_renew: bool,
+}
+
+impl<'a> GetDepositAddress<'a> {
+    pub fn builder() -> GetDepositAddressBuilder<'a> {
+        GetDepositAddressBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            wallet: WalletType,
+            method: MovementMethod<'a>,
+            op_renew: u8,
+        }
+
+        let p = JsonParams {
+            wallet: self.wallet,
+            method: self.method,
+            op_renew: self.op_renew as u8,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for GetDepositAddress<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/deposit/address")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        !self.op_renew
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct GetDepositAddressResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub address: DepositAddress,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for GetDepositAddressResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct GetDepositAddressRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            DepositAddressRaw,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<GetDepositAddressRawResp> for GetDepositAddressResp {
+            fn from(value: GetDepositAddressRawResp) -> Self {
+                let GetDepositAddressRawResp(mts, ty, message_id, _, address, code, status, text) =
+                    value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    address: address.into(),
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = GetDepositAddressRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
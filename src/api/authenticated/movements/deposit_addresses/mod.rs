@@ -0,0 +1,63 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+
+use crate::api::endpoint::Endpoint;
+
+use super::types::{DepositAddress, MovementMethod};
+
+/// https://docs.bitfinex.com/reference/deposit-address-all
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct DepositAddresses<'a> {
+    method: MovementMethod<'a>,
+    #[builder(default)]
+    page: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl<'a> DepositAddresses<'a> {
+    pub fn builder() -> DepositAddressesBuilder<'a> {
+        DepositAddressesBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            method: MovementMethod<'a>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            page: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+        }
+
+        let p = JsonParams {
+            method: self.method,
+            page: self.page,
+            limit: self.limit,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for DepositAddresses<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/deposit/address/all")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type DepositAddressesResp = Vec<DepositAddress>;
@@ -0,0 +1,112 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{authenticated::wallets::WalletType, common::PlaceHolder, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-deposit-invoice
+///
+/// Generates a Lightning Network invoice to deposit BTC (`LNX`).
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct DepositInvoice<'a> {
+    #[builder(default = "\"LNX\"")]
+    currency: &'a str,
+    wallet: WalletType,
+    amount: f64,
+}
+
+impl<'a> DepositInvoiceBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        match self.amount {
+            Some(amount) if !(amount.is_finite() && amount > 0.) => {
+                Err(format!("Invalid deposit amount {amount}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> DepositInvoice<'a> {
+    pub fn builder() -> DepositInvoiceBuilder<'a> {
+        DepositInvoiceBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            currency: &'a str,
+            wallet: WalletType,
+            #[serde_as(as = "serde_with::DisplayFromStr")]
+            amount: f64,
+        }
+
+        let p = JsonParams {
+            currency: self.currency,
+            wallet: self.wallet,
+            amount: self.amount,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for DepositInvoice<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/deposit/invoice")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct DepositInvoiceResp {
+    pub invoice_hash: String,
+    pub invoice: String,
+    pub amount: f64,
+}
+
+impl<'de> Deserialize<'de> for DepositInvoiceResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[serde_as]
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct DepositInvoiceRawResp(
+            String,
+            String,
+            PlaceHolder,
+            PlaceHolder,
+            #[serde_as(as = "serde_with::PickFirst<(_, serde_with::DisplayFromStr)>")] f64,
+        );
+
+        impl From<DepositInvoiceRawResp> for DepositInvoiceResp {
+            fn from(value: DepositInvoiceRawResp) -> Self {
+                let DepositInvoiceRawResp(invoice_hash, invoice, _, _, amount) = value;
+
+                Self {
+                    invoice_hash,
+                    invoice,
+                    amount,
+                }
+            }
+        }
+
+        let raw = DepositInvoiceRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
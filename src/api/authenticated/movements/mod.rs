@@ -0,0 +1,7 @@
+pub mod deposit_address;
+pub mod deposit_addresses;
+pub mod deposit_invoice;
+pub mod movement_info;
+pub mod movements_history;
+pub mod types;
+pub mod withdraw;
@@ -0,0 +1,166 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::Movement;
+
+/// https://docs.bitfinex.com/reference/movement-info
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct MovementInfo {
+    id: u64,
+}
+
+impl MovementInfo {
+    pub fn builder() -> MovementInfoBuilder {
+        MovementInfoBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            id: u64,
+        }
+
+        let p = JsonParams { id: self.id };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for MovementInfo {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/movements/info")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+/// A [`Movement`] along with the details of the bank transfer, for the fiat movements.
+#[derive(Debug)]
+pub struct MovementInfoResp {
+    pub movement: Movement,
+    pub bank_fees: Option<f64>,
+    pub bank_router_id: Option<u64>,
+    pub external_bank_mov_id: Option<String>,
+    pub external_bank_mov_status: Option<String>,
+    pub external_bank_mov_description: Option<String>,
+    pub external_bank_acc_info: Option<Value>,
+}
+
+impl<'de> Deserialize<'de> for MovementInfoResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct MovementInfoRawResp(
+            u64,
+            String,
+            String,
+            PlaceHolder,
+            PlaceHolder,
+            u64,
+            u64,
+            PlaceHolder,
+            PlaceHolder,
+            String,
+            PlaceHolder,
+            PlaceHolder,
+            f64,
+            f64,
+            PlaceHolder,
+            PlaceHolder,
+            Option<String>,
+            Option<String>,
+            PlaceHolder,
+            PlaceHolder,
+            Option<String>,
+            Option<String>,
+            #[serde(default)] Option<f64>,
+            #[serde(default)] Option<u64>,
+            #[serde(default)] PlaceHolder,
+            #[serde(default)] PlaceHolder,
+            #[serde(default)] Option<String>,
+            #[serde(default)] Option<String>,
+            #[serde(default)] Option<String>,
+            #[serde(default)] Option<Value>,
+        );
+
+        impl From<MovementInfoRawResp> for MovementInfoResp {
+            fn from(value: MovementInfoRawResp) -> Self {
+                let MovementInfoRawResp(
+                    id,
+                    currency,
+                    currency_name,
+                    _,
+                    _,
+                    mts_started,
+                    mts_updated,
+                    _,
+                    _,
+                    status,
+                    _,
+                    _,
+                    amount,
+                    fees,
+                    _,
+                    _,
+                    destination_address,
+                    payment_id,
+                    _,
+                    _,
+                    transaction_id,
+                    withdraw_transaction_note,
+                    bank_fees,
+                    bank_router_id,
+                    _,
+                    _,
+                    external_bank_mov_id,
+                    external_bank_mov_status,
+                    external_bank_mov_description,
+                    external_bank_acc_info,
+                ) = value;
+
+                Self {
+                    movement: Movement {
+                        id,
+                        currency,
+                        currency_name,
+                        mts_started,
+                        mts_updated,
+                        status,
+                        amount,
+                        fees,
+                        destination_address,
+                        payment_id,
+                        transaction_id,
+                        withdraw_transaction_note,
+                    },
+                    bank_fees,
+                    bank_router_id,
+                    external_bank_mov_id,
+                    external_bank_mov_status,
+                    external_bank_mov_description,
+                    external_bank_acc_info,
+                }
+            }
+        }
+
+        let raw = MovementInfoRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,81 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+
+use crate::api::endpoint::Endpoint;
+
+use super::types::Movement;
+
+/// https://docs.bitfinex.com/reference/rest-auth-movements
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct MovementsHistory<'a> {
+    /// The currency of the movements (e.g. `BTC`), all the currencies when not set.
+    #[builder(default)]
+    currency: Option<&'a str>,
+    #[builder(default)]
+    start: Option<u64>,
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+    #[builder(default)]
+    id: Option<Vec<u64>>,
+    #[builder(default)]
+    address: Option<&'a str>,
+}
+
+impl<'a> MovementsHistory<'a> {
+    pub fn builder() -> MovementsHistoryBuilder<'a> {
+        MovementsHistoryBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            id: Option<&'a [u64]>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            address: Option<&'a str>,
+        }
+
+        let p = JsonParams {
+            start: self.start,
+            end: self.end,
+            limit: self.limit,
+            id: self.id.as_ref().map(|ids| &ids[..]),
+            address: self.address,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for MovementsHistory<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        match self.currency {
+            Some(currency) => format!("v2/auth/r/movements/{currency}/hist"),
+            None => String::from("v2/auth/r/movements/hist"),
+        }
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type MovementsHistoryResp = Vec<Movement>;
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::common::PlaceHolder;
+
+/// The deposit and withdrawal methods, i.e. the network used to move the funds.
+///
+/// The methods supported for a currency are listed by `v2/conf/pub:map:tx:method`, use `Other` for those
+/// without a variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MovementMethod<'a> {
+    Bitcoin,
+    Litecoin,
+    Ethereum,
+    /// USDt on Ethereum (ERC20).
+    #[serde(rename = "tetheruse")]
+    TetherEthereum,
+    /// USDt on Tron (TRC20).
+    #[serde(rename = "tetherusx")]
+    TetherTron,
+    Tron,
+    Ripple,
+    Monero,
+    Dogecoin,
+    /// Bitcoin on the Lightning Network.
+    Lnx,
+    #[serde(untagged)]
+    Other(&'a str),
+}
+
+#[derive(Debug)]
+pub struct Movement {
+    pub id: u64,
+    pub currency: String,
+    pub currency_name: String,
+    pub mts_started: u64,
+    pub mts_updated: u64,
+    pub status: String,
+    /// Positive for deposits and negative for withdrawals.
+    pub amount: f64,
+    pub fees: f64,
+    pub destination_address: Option<String>,
+    pub payment_id: Option<String>,
+    pub transaction_id: Option<String>,
+    pub withdraw_transaction_note: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for Movement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = MovementRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+pub struct MovementRaw(
+    u64,
+    String,
+    String,
+    PlaceHolder,
+    PlaceHolder,
+    u64,
+    u64,
+    PlaceHolder,
+    PlaceHolder,
+    String,
+    PlaceHolder,
+    PlaceHolder,
+    f64,
+    f64,
+    PlaceHolder,
+    PlaceHolder,
+    Option<String>,
+    Option<String>,
+    PlaceHolder,
+    PlaceHolder,
+    Option<String>,
+    Option<String>,
+);
+
+impl From<MovementRaw> for Movement {
+    fn from(value: MovementRaw) -> Self {
+        let MovementRaw(
+            id,
+            currency,
+            currency_name,
+            _,
+            _,
+            mts_started,
+            mts_updated,
+            _,
+            _,
+            status,
+            _,
+            _,
+            amount,
+            fees,
+            _,
+            _,
+            destination_address,
+            payment_id,
+            _,
+            _,
+            transaction_id,
+            withdraw_transaction_note,
+        ) = value;
+
+        Self {
+            id,
+            currency,
+            currency_name,
+            mts_started,
+            mts_updated,
+            status,
+            amount,
+            fees,
+            destination_address,
+            payment_id,
+            transaction_id,
+            withdraw_transaction_note,
+        }
+    }
+}
+
+/// A deposit address.
+///
+/// For the currencies using a tag or memo (e.g. XRP), `address` is the tag and `pool_address` the address
+/// shared by all the users.
+#[derive(Debug)]
+pub struct DepositAddress {
+    pub method: String,
+    pub currency: String,
+    pub address: String,
+    pub pool_address: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for DepositAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = DepositAddressRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+pub struct DepositAddressRaw(
+    Option<Value>,
+    String,
+    String,
+    PlaceHolder,
+    String,
+    Option<String>,
+);
+
+impl From<DepositAddressRaw> for DepositAddress {
+    fn from(value: DepositAddressRaw) -> Self {
+        let DepositAddressRaw(_, method, currency, _, address, pool_address) = value;
+
+        Self {
+            method,
+            currency,
+            address,
+            pool_address,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{authenticated::wallets::WalletType, common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::MovementMethod;
+
+/// https://docs.bitfinex.com/reference/rest-auth-withdraw
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Withdraw<'a> {
+    wallet: WalletType,
+    method: MovementMethod<'a>,
+    amount: f64,
+    /// The destination address, or the invoice for the Lightning Network.
+    address: &'a str,
+    /// The tag or memo, for the currencies using one (e.g. XRP).
+    #[builder(default)]
+    payment_id: Option<&'a str>,
+    /// Deducts the withdrawal fee from `amount` instead of the remaining balance.
+    #[builder(default)]
+    fee_deduct: bool,
+}
+
+impl<'a> WithdrawBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(address) = self.address {
+            if address.trim().is_empty() {
+                return Err(String::from("The withdrawal address cannot be empty"));
+            }
+        }
+
+        match self.amount {
+            Some(amount) if !(amount.is_finite() && amount > 0.) => {
+                Err(format!("Invalid withdrawal amount {amount}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> Withdraw<'a> {
+    pub fn builder() -> WithdrawBuilder<'a> {
+        WithdrawBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            wallet: WalletType,
+            method: MovementMethod<'a>,
+            #[serde_as(as = "serde_with::DisplayFromStr")]
+            amount: f64,
+            address: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            payment_id: Option<&'a str>,
+            fee_deduct: u8,
+        }
+
+        let p = JsonParams {
+            wallet: self.wallet,
+            method: self.method,
+            amount: self.amount,
+            address: self.address,
+            payment_id: self.payment_id,
+            fee_deduct: self.fee_deduct as u8,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for Withdraw<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/withdraw")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Withdrawal {
+    pub id: u64,
+    pub method: String,
+    pub payment_id: Option<String>,
+    pub wallet: Option<WalletType>,
+    pub amount: f64,
+    pub fee: f64,
+}
+
+#[derive(Debug)]
+pub struct WithdrawResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub withdrawal: Withdrawal,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for WithdrawResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct WithdrawalRaw(
+            u64,
+            PlaceHolder,
+            String,
+            Option<String>,
+            Option<WalletType>,
+            f64,
+            PlaceHolder,
+            PlaceHolder,
+            f64,
+        );
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct WithdrawRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            WithdrawalRaw,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<WithdrawRawResp> for WithdrawResp {
+            fn from(value: WithdrawRawResp) -> Self {
+                let WithdrawRawResp(
+                    mts,
+                    ty,
+                    message_id,
+                    _,
+                    WithdrawalRaw(id, _, method, payment_id, wallet, amount, _, _, fee),
+                    code,
+                    status,
+                    text,
+                ) = value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    withdrawal: Withdrawal {
+                        id,
+                        method,
+                        payment_id,
+                        wallet,
+                        amount,
+                        fee,
+                    },
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = WithdrawRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -16,7 +16,7 @@ impl CancelOrder {
         CancelOrderBuilder::default()
     }
 
-    fn json_body(&self) -> String {
+    pub(crate) fn json_body(&self) -> String {
         #[derive(Debug, Serialize)]
         pub struct JsonParams {
             id: u64,
@@ -41,6 +41,10 @@ impl Endpoint for CancelOrder {
         true
     }
 
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
     fn body(&self) -> Option<(&'static str, Vec<u8>)> {
         Some(("application/json", self.json_body().into_bytes()))
     }
@@ -62,6 +66,7 @@ impl<'de> Deserialize<'de> for CancelOrderResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct CancelOrderRawResp(
             u64,
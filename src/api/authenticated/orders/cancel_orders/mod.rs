@@ -22,7 +22,7 @@ impl CancelOrders {
         CancelOrdersBuilder::default()
     }
 
-    fn json_body(&self) -> String {
+    pub(crate) fn json_body(&self) -> String {
         match &self.cancel_orders_type {
             CancelOrdersType::OnlyIds(ids) => {
                 #[derive(Debug, Serialize)]
@@ -52,6 +52,10 @@ impl Endpoint for CancelOrders {
         true
     }
 
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
     fn body(&self) -> Option<(&'static str, Vec<u8>)> {
         Some(("application/json", self.json_body().into_bytes()))
     }
@@ -61,9 +65,9 @@ impl Endpoint for CancelOrders {
 pub struct CancelOrdersResp {
     pub mts: u64,
     pub ty: String,
-    pub message_id: u64,
+    pub message_id: Option<u64>,
     pub orders: Vec<Order>,
-    pub code: u64,
+    pub code: Option<u64>,
     pub status: String,
     pub text: String,
 }
@@ -73,14 +77,15 @@ impl<'de> Deserialize<'de> for CancelOrdersResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct CancelOrdersRawResp(
             u64,
             String,
-            u64,
+            Option<u64>,
             PlaceHolder,
             Vec<OrderRaw>,
-            u64,
+            Option<u64>,
             String,
             String,
         );
@@ -1,7 +1,10 @@
 pub mod cancel_order;
 pub mod cancel_orders;
+pub mod order_multi_op;
+pub mod order_trades;
 pub mod retrieve_orders;
 pub mod retrieve_orders_by_symbol;
 pub mod submit_order;
+pub mod update_order;
 pub mod orders_history;
 pub mod types;
@@ -0,0 +1,243 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::api::{authenticated::orders::types::OrderRaw, common::PlaceHolder, endpoint::Endpoint};
+
+use super::{
+    cancel_order::CancelOrder, cancel_orders::CancelOrders, submit_order::SubmitOrder,
+    types::Order, update_order::UpdateOrder,
+};
+
+/// The maximum number of operations in a single request.
+pub const MAX_OPS: usize = 75;
+
+/// An order operation, batched with [`OrderMultiOp`] or sent on the WebSocket connection.
+#[derive(Debug, Clone, Copy)]
+pub enum OrderOp<'a> {
+    Submit(&'a SubmitOrder<'a>),
+    Update(&'a UpdateOrder<'a>),
+    Cancel(&'a CancelOrder),
+    CancelMulti(&'a CancelOrders),
+}
+
+impl<'a> OrderOp<'a> {
+    /// The code of the operation.
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            OrderOp::Submit(_) => "on",
+            OrderOp::Update(_) => "ou",
+            OrderOp::Cancel(_) => "oc",
+            OrderOp::CancelMulti(_) => "oc_multi",
+        }
+    }
+
+    /// The operation payload, as sent to the single operation endpoint.
+    pub(crate) fn json_value(&self) -> Value {
+        let body = match self {
+            OrderOp::Submit(order) => order.json_body(),
+            OrderOp::Update(order) => order.json_body(),
+            OrderOp::Cancel(order) => order.json_body(),
+            OrderOp::CancelMulti(orders) => orders.json_body(),
+        };
+
+        serde_json::from_str(&body).unwrap()
+    }
+
+    fn is_idempotent(&self) -> bool {
+        match self {
+            OrderOp::Submit(order) => order.is_idempotent(),
+            OrderOp::Update(order) => order.is_idempotent(),
+            OrderOp::Cancel(order) => order.is_idempotent(),
+            OrderOp::CancelMulti(orders) => orders.is_idempotent(),
+        }
+    }
+}
+
+/// https://docs.bitfinex.com/reference/rest-auth-order-multi
+#[derive(Debug, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct OrderMultiOp<'a> {
+    #[builder(setter(each(name = "op")))]
+    ops: Vec<OrderOp<'a>>,
+}
+
+impl<'a> OrderMultiOp<'a> {
+    pub fn builder() -> OrderMultiOpBuilder<'a> {
+        OrderMultiOpBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            ops: Vec<(&'static str, Value)>,
+        }
+
+        let p = JsonParams {
+            ops: self
+                .ops
+                .iter()
+                .map(|op| (op.code(), op.json_value()))
+                .collect(),
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> OrderMultiOpBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        let count = self.ops.as_ref().map_or(0, Vec::len);
+        if count == 0 || count > MAX_OPS {
+            return Err(format!(
+                "expected between 1 and {MAX_OPS} operations, got {count}"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Endpoint for OrderMultiOp<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/order/multi")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    /// The batch can only be replayed when all its operations can.
+    fn is_idempotent(&self) -> bool {
+        self.ops.iter().all(OrderOp::is_idempotent)
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct OrderMultiOpResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    /// The results of the operations, in the order they were sent.
+    pub results: Vec<OrderOpResult>,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for OrderMultiOpResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct OrderMultiOpRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            Vec<OrderOpResult>,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<OrderMultiOpRawResp> for OrderMultiOpResp {
+            fn from(value: OrderMultiOpRawResp) -> Self {
+                let OrderMultiOpRawResp(mts, ty, message_id, _, results, code, status, text) =
+                    value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    results,
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = OrderMultiOpRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+/// The notification of a single operation (e.g. `on-req`, `oc-req`).
+#[derive(Debug)]
+pub struct OrderOpResult {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    /// The orders affected by the operation, empty when it failed.
+    pub orders: Vec<Order>,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for OrderOpResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// `on` and `oc_multi` return a list of orders, `ou` and `oc` a single one.
+        #[derive(Debug, Deserialize)]
+        #[serde(untagged)]
+        enum OrdersRaw {
+            One(Box<OrderRaw>),
+            Many(Vec<OrderRaw>),
+        }
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct OrderOpRawResult(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            Option<OrdersRaw>,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<OrderOpRawResult> for OrderOpResult {
+            fn from(value: OrderOpRawResult) -> Self {
+                let OrderOpRawResult(mts, ty, message_id, _, orders, code, status, text) = value;
+
+                let orders = match orders {
+                    Some(OrdersRaw::One(order)) => vec![(*order).into()],
+                    Some(OrdersRaw::Many(orders)) => {
+                        orders.into_iter().map(|order| order.into()).collect()
+                    }
+                    None => vec![],
+                };
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    orders,
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = OrderOpRawResult::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
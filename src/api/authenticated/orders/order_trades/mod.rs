@@ -0,0 +1,122 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
+
+/// https://docs.bitfinex.com/reference/rest-auth-order-trades
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct OrderTrades<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+    id: u64,
+}
+
+impl<'a> OrderTrades<'a> {
+    pub fn builder() -> OrderTradesBuilder<'a> {
+        OrderTradesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for OrderTrades<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/auth/r/order/{}:{}/trades", self.symbol, self.id)
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+pub type OrderTradesResp = Vec<OrderTrade>;
+
+/// [0]  ID             int         Trade database id
+/// [1]  SYMBOL         string      Symbol (BTCUSD, …)
+/// [2]  MTS            int         Execution timestamp
+/// [3]  ORDER_ID       int         Order id
+/// [4]  EXEC_AMOUNT    float       Positive means buy, negative means sell
+/// [5]  EXEC_PRICE     float       Execution price
+/// [8]  MAKER          int         1 if true, -1 if false
+/// [9]  FEE            float       Fee
+/// [10] FEE_CURRENCY   string      Fee currency
+/// [11] CID            int         Client Order ID
+///
+#[derive(Debug)]
+pub struct OrderTrade {
+    pub id: u64,
+    pub symbol: String,
+    pub mts: u64,
+    pub order_id: u64,
+    pub exec_amount: f64,
+    pub exec_price: f64,
+    pub maker: i32,
+    pub fee: f64,
+    pub fee_currency: String,
+    pub cid: u64,
+}
+
+impl<'de> Deserialize<'de> for OrderTrade {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct OrderTradeRaw(
+            u64,
+            String,
+            u64,
+            u64,
+            f64,
+            f64,
+            PlaceHolder,
+            PlaceHolder,
+            i32,
+            f64,
+            String,
+            u64,
+        );
+
+        impl From<OrderTradeRaw> for OrderTrade {
+            fn from(value: OrderTradeRaw) -> Self {
+                let OrderTradeRaw(
+                    id,
+                    symbol,
+                    mts,
+                    order_id,
+                    exec_amount,
+                    exec_price,
+                    _,
+                    _,
+                    maker,
+                    fee,
+                    fee_currency,
+                    cid,
+                ) = value;
+
+                Self {
+                    id,
+                    symbol,
+                    mts,
+                    order_id,
+                    exec_amount,
+                    exec_price,
+                    maker,
+                    fee,
+                    fee_currency,
+                    cid,
+                }
+            }
+        }
+
+        let raw = OrderTradeRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -2,14 +2,15 @@ use derive_builder::Builder;
 use http::Method;
 use serde::Serialize;
 
-use crate::api::endpoint::Endpoint;
+use crate::api::{common::Symbol, endpoint::Endpoint};
 
 use super::types::Order;
 
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct RetrieveOrdersBySymbol<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     #[builder(default)]
     ids: Option<Vec<u64>>,
 }
@@ -3,7 +3,11 @@ use http::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use crate::api::{authenticated::orders::types::OrderRaw, common::PlaceHolder, endpoint::Endpoint};
+use crate::api::{
+    authenticated::orders::types::OrderRaw,
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
 
 use super::types::{Order, OrderFlag, OrderType};
 
@@ -11,7 +15,8 @@ use super::types::{Order, OrderFlag, OrderType};
 #[builder(setter(strip_option))]
 pub struct SubmitOrder<'a> {
     ty: OrderType,
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     amount: f64,
     price: f64,
     #[builder(default)]
@@ -37,13 +42,13 @@ impl<'a> SubmitOrder<'a> {
         SubmitOrderBuilder::default()
     }
 
-    fn json_body(&self) -> String {
+    pub(crate) fn json_body(&self) -> String {
         #[serde_as]
         #[derive(Debug, Serialize)]
         pub struct JsonParams<'a> {
             #[serde(rename(serialize = "type"))]
             ty: OrderType,
-            symbol: &'a str,
+            symbol: Symbol<'a>,
             #[serde_as(as = "serde_with::DisplayFromStr")]
             amount: f64,
             #[serde_as(as = "serde_with::DisplayFromStr")]
@@ -127,6 +132,7 @@ impl<'de> Deserialize<'de> for SubmitOrderResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct SubmitOrderRawResp(
             u64,
@@ -81,6 +81,7 @@ impl<'de> Deserialize<'de> for Order {
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 pub struct OrderRaw(
     u64,
@@ -0,0 +1,173 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{authenticated::orders::types::OrderRaw, common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::{Order, OrderFlag};
+
+/// https://docs.bitfinex.com/reference/rest-auth-update-order
+#[derive(Debug, Builder)]
+#[builder(setter(strip_option))]
+pub struct UpdateOrder<'a> {
+    id: u64,
+    #[builder(default)]
+    cid: Option<u64>,
+    #[builder(default)]
+    cid_date: Option<&'a str>,
+    #[builder(default)]
+    gid: Option<u64>,
+    #[builder(default)]
+    price: Option<f64>,
+    #[builder(default)]
+    amount: Option<f64>,
+    #[builder(default)]
+    delta: Option<f64>,
+    #[builder(default)]
+    lev: Option<u8>,
+    #[builder(default)]
+    price_aux_limit: Option<f64>,
+    #[builder(default)]
+    price_trailing: Option<f64>,
+    #[builder(default)]
+    flags: Option<Vec<OrderFlag>>,
+    #[builder(default)]
+    tif: Option<&'a str>,
+}
+
+impl<'a> UpdateOrder<'a> {
+    pub fn builder() -> UpdateOrderBuilder<'a> {
+        UpdateOrderBuilder::default()
+    }
+
+    pub(crate) fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            id: u64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cid: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cid_date: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            gid: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            price: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            amount: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            delta: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            lev: Option<u8>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            price_aux_limit: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            price_trailing: Option<f64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            flags: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            tif: Option<&'a str>,
+        }
+
+        let flags = self
+            .flags
+            .as_ref()
+            .map(|flags| flags.iter().fold(0, |sum, flag| sum + *flag as u64));
+
+        let p = JsonParams {
+            id: self.id,
+            cid: self.cid,
+            cid_date: self.cid_date,
+            gid: self.gid,
+            price: self.price,
+            amount: self.amount,
+            delta: self.delta,
+            lev: self.lev,
+            price_aux_limit: self.price_aux_limit,
+            price_trailing: self.price_trailing,
+            flags,
+            tif: self.tif,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for UpdateOrder<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/order/update")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    /// Relative updates are applied once per request.
+    fn is_idempotent(&self) -> bool {
+        self.delta.is_none()
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateOrderResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub order: Order,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for UpdateOrderResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct UpdateOrderRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            OrderRaw,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<UpdateOrderRawResp> for UpdateOrderResp {
+            fn from(value: UpdateOrderRawResp) -> Self {
+                let UpdateOrderRawResp(mts, ty, message_id, _, order, code, status, text) = value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    order: order.into(),
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = UpdateOrderRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,32 @@
+use derive_builder::Builder;
+use http::Method;
+
+use crate::api::endpoint::Endpoint;
+
+use super::types::Position;
+
+/// https://docs.bitfinex.com/reference/rest-auth-positions
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ActivePositions {}
+
+impl ActivePositions {
+    pub fn builder() -> ActivePositionsBuilder {
+        ActivePositionsBuilder::default()
+    }
+}
+
+impl Endpoint for ActivePositions {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/positions")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+}
+
+pub type ActivePositionsResp = Vec<Position>;
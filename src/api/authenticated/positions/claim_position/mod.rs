@@ -0,0 +1,116 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+
+use super::types::{Position, PositionRaw};
+
+/// https://docs.bitfinex.com/reference/rest-auth-position-claim
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct ClaimPosition {
+    id: u64,
+    /// The amount to claim, the whole position is claimed when not set.
+    #[builder(default)]
+    amount: Option<f64>,
+}
+
+impl ClaimPosition {
+    pub fn builder() -> ClaimPositionBuilder {
+        ClaimPositionBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            id: u64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde_as(as = "Option<serde_with::DisplayFromStr>")]
+            amount: Option<f64>,
+        }
+
+        let p = JsonParams {
+            id: self.id,
+            amount: self.amount,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for ClaimPosition {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/position/claim")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        // Claiming the whole position twice fails instead of claiming more.
+        self.amount.is_none()
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct ClaimPositionResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub position: Position,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for ClaimPositionResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct ClaimPositionRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            PositionRaw,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<ClaimPositionRawResp> for ClaimPositionResp {
+            fn from(value: ClaimPositionRawResp) -> Self {
+                let ClaimPositionRawResp(mts, ty, message_id, _, position, code, status, text) =
+                    value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    position: position.into(),
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = ClaimPositionRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,132 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
+
+/// https://docs.bitfinex.com/reference/rest-auth-position-increase
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct IncreasePosition<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+    amount: f64,
+}
+
+impl<'a> IncreasePosition<'a> {
+    pub fn builder() -> IncreasePositionBuilder<'a> {
+        IncreasePositionBuilder::default()
+    }
+
+    pub(crate) fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            symbol: Symbol<'a>,
+            #[serde_as(as = "serde_with::DisplayFromStr")]
+            amount: f64,
+        }
+
+        let p = JsonParams {
+            symbol: self.symbol,
+            amount: self.amount,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for IncreasePosition<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/position/increase")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct IncreasedPosition {
+    pub symbol: String,
+    pub amount: f64,
+    pub base_price: f64,
+}
+
+#[derive(Debug)]
+pub struct IncreasePositionResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub position: IncreasedPosition,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for IncreasePositionResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct IncreasedPositionRaw(String, PlaceHolder, f64, f64);
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct IncreasePositionRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            IncreasedPositionRaw,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<IncreasePositionRawResp> for IncreasePositionResp {
+            fn from(value: IncreasePositionRawResp) -> Self {
+                let IncreasePositionRawResp(
+                    mts,
+                    ty,
+                    message_id,
+                    _,
+                    IncreasedPositionRaw(symbol, _, amount, base_price),
+                    code,
+                    status,
+                    text,
+                ) = value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    position: IncreasedPosition {
+                        symbol,
+                        amount,
+                        base_price,
+                    },
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = IncreasePositionRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
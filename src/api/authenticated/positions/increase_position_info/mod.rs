@@ -0,0 +1,125 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{de::IgnoredAny, Deserialize};
+
+use crate::api::{
+    authenticated::positions::increase_position::IncreasePosition,
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
+
+/// https://docs.bitfinex.com/reference/rest-auth-increase-position-info
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct IncreasePositionInfo<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+    amount: f64,
+}
+
+impl<'a> IncreasePositionInfo<'a> {
+    pub fn builder() -> IncreasePositionInfoBuilder<'a> {
+        IncreasePositionInfoBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for IncreasePositionInfo<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/position/increase/info")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        let increase = IncreasePosition::builder()
+            .symbol(self.symbol)
+            .amount(self.amount)
+            .build()
+            .unwrap();
+
+        Some(("application/json", increase.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct IncreasePositionInfoResp {
+    pub max_pos: f64,
+    pub current_pos: Option<f64>,
+    pub base_currency_balance: f64,
+    pub tradable_balance_quote_currency: f64,
+    pub tradable_balance_quote_total: f64,
+    pub tradable_balance_base_currency: f64,
+    pub tradable_balance_base_total: f64,
+    pub funding_avail: f64,
+    pub funding_value: f64,
+    pub funding_required: f64,
+    pub funding_value_currency: String,
+    pub funding_required_currency: String,
+}
+
+impl<'de> Deserialize<'de> for IncreasePositionInfoResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct IncreasePositionInfoRawResp(
+            (f64, Option<f64>, f64, f64, f64, f64, f64),
+            (f64, PlaceHolder, PlaceHolder, PlaceHolder, PlaceHolder),
+            IgnoredAny,
+            (f64, f64, String, String),
+        );
+
+        impl From<IncreasePositionInfoRawResp> for IncreasePositionInfoResp {
+            fn from(value: IncreasePositionInfoRawResp) -> Self {
+                let IncreasePositionInfoRawResp(
+                    (
+                        max_pos,
+                        current_pos,
+                        base_currency_balance,
+                        tradable_balance_quote_currency,
+                        tradable_balance_quote_total,
+                        tradable_balance_base_currency,
+                        tradable_balance_base_total,
+                    ),
+                    (funding_avail, _, _, _, _),
+                    _,
+                    (
+                        funding_value,
+                        funding_required,
+                        funding_value_currency,
+                        funding_required_currency,
+                    ),
+                ) = value;
+
+                Self {
+                    max_pos,
+                    current_pos,
+                    base_currency_balance,
+                    tradable_balance_quote_currency,
+                    tradable_balance_quote_total,
+                    tradable_balance_base_currency,
+                    tradable_balance_base_total,
+                    funding_avail,
+                    funding_value,
+                    funding_required,
+                    funding_value_currency,
+                    funding_required_currency,
+                }
+            }
+        }
+
+        let raw = IncreasePositionInfoRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,8 @@
+pub mod active_positions;
+pub mod claim_position;
+pub mod increase_position;
+pub mod increase_position_info;
+pub mod positions_audit;
+pub mod positions_history;
+pub mod positions_snapshot;
+pub mod types;
@@ -0,0 +1,70 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+
+use crate::api::endpoint::Endpoint;
+
+use super::types::Position;
+
+/// https://docs.bitfinex.com/reference/rest-auth-positions-audit
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct PositionsAudit {
+    #[builder(default)]
+    id: Option<Vec<u64>>,
+    #[builder(default)]
+    start: Option<u64>,
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl PositionsAudit {
+    pub fn builder() -> PositionsAuditBuilder {
+        PositionsAuditBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            id: Option<&'a [u64]>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u64>,
+        }
+
+        let p = JsonParams {
+            id: self.id.as_ref().map(|ids| &ids[..]),
+            start: self.start,
+            end: self.end,
+            limit: self.limit,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for PositionsAudit {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/positions/audit")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type PositionsAuditResp = Vec<Position>;
@@ -0,0 +1,125 @@
+use serde::Deserialize;
+use serde_repr::Deserialize_repr;
+
+use crate::api::common::PlaceHolder;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize_repr)]
+#[repr(u8)]
+pub enum MarginFundingType {
+    Daily = 0,
+    Term = 1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize_repr)]
+#[repr(u8)]
+pub enum PositionType {
+    Margin = 0,
+    Derivatives = 1,
+}
+
+/// https://docs.bitfinex.com/reference/rest-auth-positions
+///
+/// The P/L, liquidation price and leverage fields are only filled in when computed by the platform,
+/// the trailing fields are missing from the history endpoints.
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub symbol: String,
+    pub status: String,
+    pub amount: f64,
+    pub base_price: f64,
+    pub margin_funding: f64,
+    pub margin_funding_type: MarginFundingType,
+    pub pl: Option<f64>,
+    pub pl_perc: Option<f64>,
+    pub price_liq: Option<f64>,
+    pub leverage: Option<f64>,
+    pub position_id: u64,
+    pub mts_create: Option<u64>,
+    pub mts_update: Option<u64>,
+    pub ty: Option<PositionType>,
+    pub collateral: Option<f64>,
+    pub collateral_min: Option<f64>,
+    pub meta: Option<serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = PositionRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+pub(crate) struct PositionRaw(
+    String,
+    String,
+    f64,
+    f64,
+    f64,
+    MarginFundingType,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+    PlaceHolder,
+    u64,
+    Option<u64>,
+    Option<u64>,
+    #[serde(default)] PlaceHolder,
+    #[serde(default)] Option<PositionType>,
+    #[serde(default)] PlaceHolder,
+    #[serde(default)] Option<f64>,
+    #[serde(default)] Option<f64>,
+    #[serde(default)] Option<serde_json::Value>,
+);
+
+impl From<PositionRaw> for Position {
+    fn from(value: PositionRaw) -> Self {
+        let PositionRaw(
+            symbol,
+            status,
+            amount,
+            base_price,
+            margin_funding,
+            margin_funding_type,
+            pl,
+            pl_perc,
+            price_liq,
+            leverage,
+            _,
+            position_id,
+            mts_create,
+            mts_update,
+            _,
+            ty,
+            _,
+            collateral,
+            collateral_min,
+            meta,
+        ) = value;
+
+        Self {
+            symbol,
+            status,
+            amount,
+            base_price,
+            margin_funding,
+            margin_funding_type,
+            pl,
+            pl_perc,
+            price_liq,
+            leverage,
+            position_id,
+            mts_create,
+            mts_update,
+            ty,
+            collateral,
+            collateral_min,
+            meta,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+
+use crate::api::{endpoint::Endpoint, public::pulse::common::Pulse};
+
+/// https://docs.bitfinex.com/reference/rest-auth-pulse-add
+///
+/// Set `parent` to the id of a pulse to post a comment on it.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct AddPulse<'a> {
+    title: &'a str,
+    content: &'a str,
+    #[builder(default)]
+    parent: Option<&'a str>,
+    #[builder(default = "true")]
+    is_public: bool,
+    #[builder(default)]
+    is_pin: bool,
+    #[builder(default)]
+    attachments: Option<Vec<&'a str>>,
+}
+
+impl<'a> AddPulse<'a> {
+    pub fn builder() -> AddPulseBuilder<'a> {
+        AddPulseBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct JsonParams<'a> {
+            title: &'a str,
+            content: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            parent: Option<&'a str>,
+            is_public: u8,
+            is_pin: u8,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            attachments: Option<&'a [&'a str]>,
+        }
+
+        let p = JsonParams {
+            title: self.title,
+            content: self.content,
+            parent: self.parent,
+            is_public: self.is_public as u8,
+            is_pin: self.is_pin as u8,
+            attachments: self.attachments.as_deref(),
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for AddPulse<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/pulse/add")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type AddPulseResp = Pulse;
@@ -0,0 +1,67 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::endpoint::Endpoint;
+
+/// https://docs.bitfinex.com/reference/rest-auth-pulse-del
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct DeletePulse<'a> {
+    pid: &'a str,
+}
+
+impl<'a> DeletePulse<'a> {
+    pub fn builder() -> DeletePulseBuilder<'a> {
+        DeletePulseBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            pid: &'a str,
+        }
+
+        let p = JsonParams { pid: self.pid };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for DeletePulse<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/pulse/del")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct DeletePulseResp {
+    pub success: bool,
+}
+
+impl<'de> Deserialize<'de> for DeletePulseResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [status] = <[u8; 1]>::deserialize(deserializer)?;
+        Ok(Self {
+            success: status == 1,
+        })
+    }
+}
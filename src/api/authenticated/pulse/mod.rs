@@ -0,0 +1,3 @@
+pub mod add_pulse;
+pub mod delete_pulse;
+pub mod pulse_history;
@@ -0,0 +1,58 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Serialize;
+
+use crate::api::{endpoint::Endpoint, public::pulse::common::Pulse};
+
+/// https://docs.bitfinex.com/reference/rest-auth-pulse-hist
+///
+/// The pulses of the account, only the public ones when `is_public` is set.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct PulseHistory {
+    #[builder(default)]
+    is_public: Option<bool>,
+}
+
+impl PulseHistory {
+    pub fn builder() -> PulseHistoryBuilder {
+        PulseHistoryBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams {
+            #[serde(
+                rename(serialize = "isPublic"),
+                skip_serializing_if = "Option::is_none"
+            )]
+            is_public: Option<u8>,
+        }
+
+        let p = JsonParams {
+            is_public: self.is_public.map(|is_public| is_public as u8),
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl Endpoint for PulseHistory {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/r/pulse/hist")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+pub type PulseHistoryResp = Vec<Pulse>;
@@ -3,14 +3,19 @@ use http::Method;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-use crate::api::common::Sort;
+use crate::api::common::{Sort, Symbol};
 use crate::api::endpoint::Endpoint;
 
 use super::orders::types::OrderType;
 
+/// https://docs.bitfinex.com/reference/rest-auth-trades
+///
+/// Only the trades on `symbol` are returned when it is set.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
-pub struct Trades {
+pub struct Trades<'a> {
+    #[builder(default, setter(into))]
+    symbol: Option<Symbol<'a>>,
     #[builder(default)]
     start: Option<u64>,
     #[builder(default)]
@@ -21,8 +26,8 @@ pub struct Trades {
     sort: Option<Sort>,
 }
 
-impl Trades {
-    pub fn builder() -> TradesBuilder {
+impl<'a> Trades<'a> {
+    pub fn builder() -> TradesBuilder<'a> {
         TradesBuilder::default()
     }
 
@@ -54,13 +59,16 @@ impl Trades {
     }
 }
 
-impl Endpoint for Trades {
+impl<'a> Endpoint for Trades<'a> {
     fn method(&self) -> Method {
         Method::POST
     }
 
     fn endpoint(&self) -> String {
-        String::from("v2/auth/r/trades/hist")
+        match self.symbol {
+            Some(symbol) => format!("v2/auth/r/trades/{symbol}/hist"),
+            None => String::from("v2/auth/r/trades/hist"),
+        }
     }
 
     fn is_authenticated(&self) -> bool {
@@ -0,0 +1,195 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::api::{authenticated::wallets::WalletType, common::PlaceHolder, endpoint::Endpoint};
+
+/// https://docs.bitfinex.com/reference/rest-auth-transfer
+///
+/// Moves funds between two wallets, converting them when `currency_to` is set (e.g. `UST` from the
+/// exchange wallet to `USTF0` in the margin wallet, used as derivatives collateral), or to a sub-account
+/// when `email_dst` is set.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Transfer<'a> {
+    from: WalletType,
+    to: WalletType,
+    currency: &'a str,
+    #[builder(default)]
+    currency_to: Option<&'a str>,
+    amount: f64,
+    #[builder(default)]
+    email_dst: Option<&'a str>,
+}
+
+impl<'a> TransferBuilder<'a> {
+    fn validate(&self) -> Result<(), String> {
+        let is_conversion = matches!(self.currency_to, Some(Some(_)));
+        let is_sub_account = matches!(self.email_dst, Some(Some(_)));
+
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from == to && !is_conversion && !is_sub_account {
+                return Err(format!(
+                    "Cannot transfer from the {from:?} wallet to itself without a conversion or a destination email"
+                ));
+            }
+        }
+
+        match self.amount {
+            Some(amount) if !(amount.is_finite() && amount > 0.) => {
+                Err(format!("Invalid transfer amount {amount}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> Transfer<'a> {
+    pub fn builder() -> TransferBuilder<'a> {
+        TransferBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[serde_as]
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            from: WalletType,
+            to: WalletType,
+            currency: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            currency_to: Option<&'a str>,
+            #[serde_as(as = "serde_with::DisplayFromStr")]
+            amount: f64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            email_dst: Option<&'a str>,
+        }
+
+        let p = JsonParams {
+            from: self.from,
+            to: self.to,
+            currency: self.currency,
+            currency_to: self.currency_to,
+            amount: self.amount,
+            email_dst: self.email_dst,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for Transfer<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/auth/w/transfer")
+    }
+
+    fn is_authenticated(&self) -> bool {
+        true
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct TransferInfo {
+    pub mts_updated: u64,
+    pub wallet_from: WalletType,
+    pub wallet_to: WalletType,
+    pub currency: String,
+    pub currency_to: Option<String>,
+    pub amount: f64,
+}
+
+#[derive(Debug)]
+pub struct TransferResp {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub transfer: TransferInfo,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl<'de> Deserialize<'de> for TransferResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct TransferInfoRaw(
+            u64,
+            WalletType,
+            WalletType,
+            PlaceHolder,
+            String,
+            Option<String>,
+            PlaceHolder,
+            f64,
+        );
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct TransferRawResp(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            TransferInfoRaw,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<TransferRawResp> for TransferResp {
+            fn from(value: TransferRawResp) -> Self {
+                let TransferRawResp(
+                    mts,
+                    ty,
+                    message_id,
+                    _,
+                    TransferInfoRaw(
+                        mts_updated,
+                        wallet_from,
+                        wallet_to,
+                        _,
+                        currency,
+                        currency_to,
+                        _,
+                        amount,
+                    ),
+                    code,
+                    status,
+                    text,
+                ) = value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    transfer: TransferInfo {
+                        mts_updated,
+                        wallet_from,
+                        wallet_to,
+                        currency,
+                        currency_to,
+                        amount,
+                    },
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = TransferRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -1,18 +1,148 @@
 use std::fmt::Display;
 
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
 pub type PlaceHolder = Option<()>;
 
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid symbol `{symbol}`")]
+pub struct SymbolError {
+    pub symbol: String,
+}
+
+/// A trading pair (e.g. `tBTCUSD`, `tBTCF0:USTF0`) or a funding currency (e.g. `fUSD`).
+///
+/// The pairs whose base or quote isn't 3 characters long are displayed with a colon, as expected
+/// by Bitfinex. `Raw` symbols are sent as is, strings that can't be parsed are converted to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symbol<'a> {
+    TradingPair { base: &'a str, quote: &'a str },
+    FundingCurrency(&'a str),
+    Raw(&'a str),
+}
+
+impl<'a> Symbol<'a> {
+    pub fn trading(base: &'a str, quote: &'a str) -> Self {
+        Symbol::TradingPair { base, quote }
+    }
+
+    pub fn funding(currency: &'a str) -> Self {
+        Symbol::FundingCurrency(currency)
+    }
+
+    /// Parses a prefixed symbol, the result is displayed exactly as `symbol`.
+    pub fn parse(symbol: &'a str) -> Result<Self, SymbolError> {
+        fn is_code(code: &str) -> bool {
+            !code.is_empty()
+                && code
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        }
+
+        let parsed = match (symbol.get(..1), symbol.get(1..)) {
+            (Some("f"), Some(currency)) if is_code(currency) => Some(Symbol::funding(currency)),
+            (Some("t"), Some(pair)) => match pair.split_once(':') {
+                Some((base, quote))
+                    if is_code(base) && is_code(quote) && (base.len() != 3 || quote.len() != 3) =>
+                {
+                    Some(Symbol::trading(base, quote))
+                }
+                None if pair.len() == 6 && is_code(pair) => {
+                    Some(Symbol::trading(&pair[..3], &pair[3..]))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        parsed.ok_or_else(|| SymbolError {
+            symbol: symbol.to_string(),
+        })
+    }
+
+    pub fn is_trading(&self) -> bool {
+        match self {
+            Symbol::TradingPair { .. } => true,
+            Symbol::FundingCurrency(_) => false,
+            Symbol::Raw(symbol) => symbol.starts_with('t'),
+        }
+    }
+
+    pub fn is_funding(&self) -> bool {
+        match self {
+            Symbol::TradingPair { .. } => false,
+            Symbol::FundingCurrency(_) => true,
+            Symbol::Raw(symbol) => symbol.starts_with('f'),
+        }
+    }
+
+    /// Whether the symbol is a derivative pair, whose base and quote end with `F0` (e.g. `tBTCF0:USTF0`).
+    pub fn is_derivative(&self) -> bool {
+        match self {
+            Symbol::TradingPair { base, quote } => base.ends_with("F0") && quote.ends_with("F0"),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> Display for Symbol<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Symbol::TradingPair { base, quote } if base.len() == 3 && quote.len() == 3 => {
+                write!(f, "t{base}{quote}")
+            }
+            Symbol::TradingPair { base, quote } => write!(f, "t{base}:{quote}"),
+            Symbol::FundingCurrency(currency) => write!(f, "f{currency}"),
+            Symbol::Raw(symbol) => write!(f, "{symbol}"),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Symbol<'a> {
+    fn from(symbol: &'a str) -> Self {
+        Symbol::parse(symbol).unwrap_or(Symbol::Raw(symbol))
+    }
+}
+
+impl<'a> From<&'a String> for Symbol<'a> {
+    fn from(symbol: &'a String) -> Self {
+        Symbol::from(symbol.as_str())
+    }
+}
+
+impl<'a> Serialize for Symbol<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Symbols<'a> {
     All,
-    Only(Vec<&'a str>),
+    Only(Vec<Symbol<'a>>),
 }
 
 impl<'a> Symbols<'a> {
+    /// Only the given symbols, typed or as strings.
+    pub fn only<S>(symbols: impl IntoIterator<Item = S>) -> Self
+    where
+        S: Into<Symbol<'a>>,
+    {
+        Symbols::Only(symbols.into_iter().map(Into::into).collect())
+    }
+
     pub fn as_query_string(&self) -> String {
         match self {
             Symbols::All => String::from("ALL"),
-            Symbols::Only(symbols) => symbols.to_vec().join(","),
+            Symbols::Only(symbols) => symbols
+                .iter()
+                .map(Symbol::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
         }
     }
 }
@@ -10,6 +10,7 @@ use crate::api::{
     params::QueryParams,
     query::{url_to_http_uri, AsyncQuery, Query},
 };
+use crate::{rate_limit::EndpointPath, retry::Idempotent};
 
 /// A trait for providing the necessary information for a single REST API endpoint.
 pub trait Endpoint {
@@ -24,8 +25,16 @@ pub trait Endpoint {
         false
     }
 
+    /// Whether sending the request more than once has the same effect as sending it once.
+    ///
+    /// Failed requests that are not idempotent are only retried when the server is known not to have
+    /// processed them. By default, only the `v2/auth/w/` endpoints are considered not idempotent.
+    fn is_idempotent(&self) -> bool {
+        !self.endpoint().contains("/auth/w/")
+    }
+
     /// Query parameters for the endpoint.
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 
@@ -55,7 +64,9 @@ where
 
         let request_builder = Request::builder()
             .method(self.method())
-            .uri(url_to_http_uri(url));
+            .uri(url_to_http_uri(url))
+            .extension(Idempotent(self.is_idempotent()))
+            .extension(EndpointPath(endpoint.clone()));
 
         // Add the body to the request if any.
         let (request_builder, data) = if let Some((mime, data)) = self.body() {
@@ -77,12 +88,8 @@ where
             },
         })?;
 
-        if !status.is_success() {
-            return Err(ApiError::ServerError {
-                status,
-                source: ServerError::NotSuccess { obj: v },
-            });
-        }
+        let v = ServerError::from_response(status, v)
+            .map_err(|source| ApiError::ServerError { status, source })?;
 
         // Deserialize into whatever type the caller is asking.
         serde_json::from_value::<T>(v.clone()).map_err(|e| ApiError::DataType {
@@ -112,7 +119,9 @@ where
 
         let request_builder = Request::builder()
             .method(self.method())
-            .uri(url_to_http_uri(url));
+            .uri(url_to_http_uri(url))
+            .extension(Idempotent(self.is_idempotent()))
+            .extension(EndpointPath(endpoint.clone()));
 
         // Add the body to the request if any.
         let (request_builder, data) = if let Some((mime, data)) = self.body() {
@@ -136,12 +145,8 @@ where
             },
         })?;
 
-        if !status.is_success() {
-            return Err(ApiError::ServerError {
-                status,
-                source: ServerError::NotSuccess { obj: v },
-            });
-        }
+        let v = ServerError::from_response(status, v)
+            .map_err(|source| ApiError::ServerError { status, source })?;
 
         // Deserialize into whatever type the caller is asking.
         serde_json::from_value::<T>(v.clone()).map_err(|e| ApiError::DataType {
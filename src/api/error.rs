@@ -1,7 +1,141 @@
-use std::error::Error;
+use std::{error::Error, fmt::Display};
 
+use http::StatusCode;
+use serde_json::Value;
 use thiserror::Error;
 
+/// The error codes returned by Bitfinex.
+///
+/// https://docs.bitfinex.com/docs/abbreviations-glossary#error-codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// 10000: unknown error.
+    Unknown,
+    /// 10001: generic error.
+    Generic,
+    /// 10008: concurrency error.
+    Concurrency,
+    /// 10020: invalid request parameters.
+    Params,
+    /// 10050: configuration setup failed.
+    ConfFail,
+    /// 10100: authentication failure.
+    AuthFail,
+    /// 10111: error in the authentication payload.
+    AuthPayload,
+    /// 10112: error in the authentication signature.
+    AuthSig,
+    /// 10113: error in the authentication HMAC.
+    AuthHmac,
+    /// 10114: nonce too small.
+    NonceSmall,
+    /// 10200: error in un-authentication.
+    UnauthFail,
+    /// 10300: subscription failed.
+    SubFail,
+    /// 10301: already subscribed.
+    SubMulti,
+    /// 10400: unsubscription failed.
+    UnsubFail,
+    /// 11000: not ready, try again later.
+    Ready,
+    /// 11010: rate limit reached.
+    RateLimit,
+    /// Any other code.
+    Other(u64),
+}
+
+impl ErrorCode {
+    pub fn code(&self) -> u64 {
+        match self {
+            ErrorCode::Unknown => 10000,
+            ErrorCode::Generic => 10001,
+            ErrorCode::Concurrency => 10008,
+            ErrorCode::Params => 10020,
+            ErrorCode::ConfFail => 10050,
+            ErrorCode::AuthFail => 10100,
+            ErrorCode::AuthPayload => 10111,
+            ErrorCode::AuthSig => 10112,
+            ErrorCode::AuthHmac => 10113,
+            ErrorCode::NonceSmall => 10114,
+            ErrorCode::UnauthFail => 10200,
+            ErrorCode::SubFail => 10300,
+            ErrorCode::SubMulti => 10301,
+            ErrorCode::UnsubFail => 10400,
+            ErrorCode::Ready => 11000,
+            ErrorCode::RateLimit => 11010,
+            ErrorCode::Other(code) => *code,
+        }
+    }
+}
+
+impl From<u64> for ErrorCode {
+    fn from(code: u64) -> Self {
+        match code {
+            10000 => ErrorCode::Unknown,
+            10001 => ErrorCode::Generic,
+            10008 => ErrorCode::Concurrency,
+            10020 => ErrorCode::Params,
+            10050 => ErrorCode::ConfFail,
+            10100 => ErrorCode::AuthFail,
+            10111 => ErrorCode::AuthPayload,
+            10112 => ErrorCode::AuthSig,
+            10113 => ErrorCode::AuthHmac,
+            10114 => ErrorCode::NonceSmall,
+            10200 => ErrorCode::UnauthFail,
+            10300 => ErrorCode::SubFail,
+            10301 => ErrorCode::SubMulti,
+            10400 => ErrorCode::UnsubFail,
+            11000 => ErrorCode::Ready,
+            11010 => ErrorCode::RateLimit,
+            code => ErrorCode::Other(code),
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// An error returned by Bitfinex as `["error", CODE, MESSAGE]`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Bitfinex error {}: {}", code, message)]
+pub struct BitfinexError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl BitfinexError {
+    /// Decodes an error payload, the `{"error": "ERR_RATE_LIMIT"}` payload of the public endpoints
+    /// is decoded as an [`ErrorCode::RateLimit`] error.
+    pub fn from_value(v: &Value) -> Option<Self> {
+        match v {
+            Value::Array(items) if items.first().and_then(Value::as_str) == Some("error") => {
+                Some(Self {
+                    code: items.get(1).and_then(Value::as_u64).unwrap_or(10000).into(),
+                    message: items
+                        .get(2)
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+            }
+            Value::Object(obj) => {
+                let message = obj.get("error")?.as_str()?.to_string();
+                let code = match message.as_str() {
+                    "ERR_RATE_LIMIT" => ErrorCode::RateLimit,
+                    _ => ErrorCode::Unknown,
+                };
+
+                Some(Self { code, message })
+            }
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ServerError {
     /// A malformed JSON payload has been returned.
@@ -14,6 +148,58 @@ pub enum ServerError {
     /// The server returned a non success status code.
     #[error("Status code is not success")]
     NotSuccess { obj: serde_json::Value },
+
+    /// The server returned a Bitfinex error.
+    #[error("{}", source)]
+    Bitfinex { source: BitfinexError },
+
+    /// The server returned a notification with an `ERROR` or `FAILURE` status.
+    #[error("Request failed with status {}: {}", status, text)]
+    Notification {
+        /// The notification code, if any.
+        code: Option<u64>,
+
+        /// The notification status.
+        status: String,
+
+        /// The notification text.
+        text: String,
+
+        /// The notification.
+        obj: serde_json::Value,
+    },
+}
+
+impl ServerError {
+    /// Extracts the error carried by a response, either with a non success status or as a
+    /// `[MTS, TYPE, MESSAGE_ID, null, INFO, CODE, STATUS, TEXT]` notification whose status is `ERROR` or `FAILURE`.
+    pub fn from_response(status: StatusCode, v: Value) -> Result<Value, Self> {
+        if !status.is_success() {
+            return Err(match BitfinexError::from_value(&v) {
+                Some(source) => ServerError::Bitfinex { source },
+                None => ServerError::NotSuccess { obj: v },
+            });
+        }
+
+        let failed = match v.as_array().map(Vec::as_slice) {
+            Some(
+                [Value::Number(_), Value::String(_), _, _, _, code, Value::String(status), Value::String(text)],
+            ) if status == "ERROR" || status == "FAILURE" => {
+                Some((code.as_u64(), status.clone(), text.clone()))
+            }
+            _ => None,
+        };
+
+        match failed {
+            Some((code, status, text)) => Err(ServerError::Notification {
+                code,
+                status,
+                text,
+                obj: v,
+            }),
+            None => Ok(v),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -7,6 +7,7 @@ use super::{
     error::{ApiError, ServerError},
     query::{url_to_http_uri, AsyncQuery, Query},
 };
+use crate::{rate_limit::EndpointPath, retry::Idempotent};
 
 /// A query modifier that ignores the data returned from an endpoint.
 #[derive(Debug, Clone, Copy)]
@@ -35,7 +36,9 @@ where
 
         let request_builder = Request::builder()
             .method(self.endpoint.method())
-            .uri(url_to_http_uri(url));
+            .uri(url_to_http_uri(url))
+            .extension(Idempotent(self.endpoint.is_idempotent()))
+            .extension(EndpointPath(endpoint.clone()));
 
         // Add the body to the request if any.
         let (request_builder, data) = if let Some((mime, data)) = self.endpoint.body() {
@@ -50,19 +53,21 @@ where
         // Check the response status and extract errors if needed.
         let status = rsp.status();
 
-        if !status.is_success() {
-            // For debug purposes try to deseralize the error.
-            let v = serde_json::from_slice(rsp.body()).map_err(|_e| ApiError::ServerError {
-                status,
-                source: ServerError::InvalidJson {
-                    data: rsp.body().into_iter().copied().collect(),
-                },
-            })?;
-
-            return Err(ApiError::ServerError {
-                status,
-                source: ServerError::NotSuccess { obj: v },
-            });
+        // The data is only deserialized to extract the errors, including the failed notifications of the write endpoints.
+        match serde_json::from_slice(rsp.body()) {
+            Ok(v) => {
+                ServerError::from_response(status, v)
+                    .map_err(|source| ApiError::ServerError { status, source })?;
+            }
+            Err(_) if !status.is_success() => {
+                return Err(ApiError::ServerError {
+                    status,
+                    source: ServerError::InvalidJson {
+                        data: rsp.body().into_iter().copied().collect(),
+                    },
+                })
+            }
+            Err(_) => {}
         }
 
         // Skip the deserialization process.
@@ -88,7 +93,9 @@ where
 
         let request_builder = Request::builder()
             .method(self.endpoint.method())
-            .uri(url_to_http_uri(url));
+            .uri(url_to_http_uri(url))
+            .extension(Idempotent(self.endpoint.is_idempotent()))
+            .extension(EndpointPath(endpoint.clone()));
 
         // Add the body to the request if any.
         let (request_builder, data) = if let Some((mime, data)) = self.endpoint.body() {
@@ -105,19 +112,21 @@ where
         // Check the response status and extract errors if needed.
         let status = rsp.status();
 
-        if !status.is_success() {
-            // For debug purposes try to deseralize the error.
-            let v = serde_json::from_slice(rsp.body()).map_err(|_e| ApiError::ServerError {
-                status,
-                source: ServerError::InvalidJson {
-                    data: rsp.body().into_iter().copied().collect(),
-                },
-            })?;
-
-            return Err(ApiError::ServerError {
-                status,
-                source: ServerError::NotSuccess { obj: v },
-            });
+        // The data is only deserialized to extract the errors, including the failed notifications of the write endpoints.
+        match serde_json::from_slice(rsp.body()) {
+            Ok(v) => {
+                ServerError::from_response(status, v)
+                    .map_err(|source| ApiError::ServerError { status, source })?;
+            }
+            Err(_) if !status.is_success() => {
+                return Err(ApiError::ServerError {
+                    status,
+                    source: ServerError::InvalidJson {
+                        data: rsp.body().into_iter().copied().collect(),
+                    },
+                })
+            }
+            Err(_) => {}
         }
 
         // Skip the deserialization process.
@@ -5,9 +5,9 @@ use http::Method;
 use serde::Deserialize;
 
 use super::common::Len;
-use crate::api::{endpoint::Endpoint, params::QueryParams};
+use crate::api::{common::Symbol, endpoint::Endpoint, params::QueryParams};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Precision {
     P0,
     P1,
@@ -31,7 +31,8 @@ impl Display for Precision {
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Book<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     precision: Precision,
     #[builder(default)]
     len: Option<Len>,
@@ -52,7 +53,7 @@ impl<'a> Endpoint for Book<'a> {
         format!("v2/book/{}/{}", self.symbol, self.precision)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("len", self.len.map(|len| len as u8));
         params
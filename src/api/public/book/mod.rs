@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod book;
 pub mod common;
 pub mod raw_book;
@@ -3,12 +3,13 @@ use http::Method;
 use serde::Deserialize;
 
 use super::common::Len;
-use crate::api::{endpoint::Endpoint, params::QueryParams};
+use crate::api::{common::Symbol, endpoint::Endpoint, params::QueryParams};
 
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct RawBook<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     #[builder(default)]
     len: Option<Len>,
 }
@@ -28,7 +29,7 @@ impl<'a> Endpoint for RawBook<'a> {
         format!("v2/book/{}/R0", self.symbol)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push_opt("len", self.len.map(|len| len as u8));
         params
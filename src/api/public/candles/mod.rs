@@ -5,7 +5,7 @@ use http::Method;
 use serde::Deserialize;
 
 use crate::api::{
-    common::{Section, Sort, TimeFrame},
+    common::{Section, Sort, Symbol, TimeFrame},
     endpoint::Endpoint,
     params::QueryParams,
 };
@@ -14,16 +14,16 @@ use crate::api::{
 pub enum AvailableCandles<'a> {
     TradingCandles {
         time_frame: TimeFrame,
-        trading_pair: &'a str,
+        trading_pair: Symbol<'a>,
     },
     FundingCandles {
         time_frame: TimeFrame,
-        currency: &'a str,
+        currency: Symbol<'a>,
         period: u8,
     },
     AggregateFundingCandles {
         time_frame: TimeFrame,
-        currency: &'a str,
+        currency: Symbol<'a>,
         aggregation: u8,
         period_start: u8,
         period_end: u8,
@@ -86,7 +86,7 @@ impl<'a> Endpoint for Candles<'a> {
         format!("v2/candles/{}/{}", self.candles, self.section)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("sort", self.sort.map(|sort| sort as i8))
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, fmt::Display};
+
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::endpoint::Endpoint;
+
+/// The configuration keys decoding to a [`ConfListResp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfListKey {
+    /// The exchange trading pairs (e.g. `BTCUSD`).
+    ExchangePairs,
+    /// The margin trading pairs.
+    MarginPairs,
+    /// The derivative pairs (e.g. `BTCF0:USTF0`).
+    FuturesPairs,
+    /// The currencies.
+    Currencies,
+}
+
+impl Display for ConfListKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfListKey::ExchangePairs => write!(f, "pub:list:pair:exchange"),
+            ConfListKey::MarginPairs => write!(f, "pub:list:pair:margin"),
+            ConfListKey::FuturesPairs => write!(f, "pub:list:pair:futures"),
+            ConfListKey::Currencies => write!(f, "pub:list:currency"),
+        }
+    }
+}
+
+/// The configuration keys decoding to a [`ConfMapResp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfMapKey {
+    /// The symbols of the currencies (e.g. `UST` to `USDT`).
+    CurrencySymbols,
+    /// The labels of the currencies (e.g. `BTC` to `Bitcoin`).
+    CurrencyLabels,
+    /// The units of the currencies.
+    CurrencyUnits,
+    /// The networks of the currencies (e.g. `UST` to `ETH`).
+    CurrencyPools,
+}
+
+impl Display for ConfMapKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfMapKey::CurrencySymbols => write!(f, "pub:map:currency:sym"),
+            ConfMapKey::CurrencyLabels => write!(f, "pub:map:currency:label"),
+            ConfMapKey::CurrencyUnits => write!(f, "pub:map:currency:unit"),
+            ConfMapKey::CurrencyPools => write!(f, "pub:map:currency:pool"),
+        }
+    }
+}
+
+/// The configuration keys decoding to a [`ConfPairInfoResp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfPairInfoKey {
+    /// The order sizes and margins of the trading pairs.
+    Pairs,
+    /// The order sizes and margins of the derivative pairs.
+    FuturesPairs,
+}
+
+impl Display for ConfPairInfoKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfPairInfoKey::Pairs => write!(f, "pub:info:pair"),
+            ConfPairInfoKey::FuturesPairs => write!(f, "pub:info:pair:futures"),
+        }
+    }
+}
+
+/// https://docs.bitfinex.com/reference/rest-public-conf
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ConfList {
+    key: ConfListKey,
+}
+
+impl ConfList {
+    pub fn builder() -> ConfListBuilder {
+        ConfListBuilder::default()
+    }
+}
+
+impl Endpoint for ConfList {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/conf/{}", self.key)
+    }
+}
+
+/// https://docs.bitfinex.com/reference/rest-public-conf
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ConfMap {
+    key: ConfMapKey,
+}
+
+impl ConfMap {
+    pub fn builder() -> ConfMapBuilder {
+        ConfMapBuilder::default()
+    }
+}
+
+impl Endpoint for ConfMap {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/conf/{}", self.key)
+    }
+}
+
+/// https://docs.bitfinex.com/reference/rest-public-conf
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ConfPairInfo {
+    key: ConfPairInfoKey,
+}
+
+impl ConfPairInfo {
+    pub fn builder() -> ConfPairInfoBuilder {
+        ConfPairInfoBuilder::default()
+    }
+}
+
+impl Endpoint for ConfPairInfo {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/conf/{}", self.key)
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfListResp {
+    pub values: Vec<String>,
+}
+
+impl<'de> Deserialize<'de> for ConfListResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [values] = <[Vec<String>; 1]>::deserialize(deserializer)?;
+        Ok(Self { values })
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfMapResp {
+    pub values: HashMap<String, String>,
+}
+
+impl<'de> Deserialize<'de> for ConfMapResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [values] = <[Vec<(String, String)>; 1]>::deserialize(deserializer)?;
+        Ok(Self {
+            values: values.into_iter().collect(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfPairInfoResp {
+    pub pairs: Vec<PairInfo>,
+}
+
+impl ConfPairInfoResp {
+    /// The info of a pair, with or without its `t` prefix (e.g. `tBTCUSD` or `BTCUSD`).
+    pub fn get(&self, pair: &str) -> Option<&PairInfo> {
+        let pair = pair.strip_prefix('t').unwrap_or(pair);
+        self.pairs.iter().find(|info| info.pair == pair)
+    }
+}
+
+/// [3] MIN_ORDER_SIZE  string  Minimum order size
+/// [4] MAX_ORDER_SIZE  string  Maximum order size
+/// [8] INITIAL_MARGIN  string  Initial margin, for the derivative pairs
+/// [9] MIN_MARGIN      string  Minimum margin, for the derivative pairs
+///
+#[derive(Debug)]
+pub struct PairInfo {
+    pub pair: String,
+    pub min_order_size: f64,
+    pub max_order_size: f64,
+    pub initial_margin: Option<f64>,
+    pub min_margin: Option<f64>,
+}
+
+impl PairInfo {
+    /// Whether an order amount, positive or negative, is within the allowed sizes.
+    pub fn is_valid_amount(&self, amount: f64) -> bool {
+        (self.min_order_size..=self.max_order_size).contains(&amount.abs())
+    }
+}
+
+impl<'de> Deserialize<'de> for PairInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // The number of fields of the info varies between pairs, the unused ones are placeholders.
+        #[derive(Debug, Deserialize)]
+        struct PairInfoRaw(String, Vec<Value>);
+
+        fn field(info: &[Value], index: usize) -> Option<f64> {
+            match info.get(index)? {
+                Value::String(value) => value.parse().ok(),
+                value => value.as_f64(),
+            }
+        }
+
+        let PairInfoRaw(pair, info) = PairInfoRaw::deserialize(deserializer)?;
+
+        Ok(Self {
+            min_order_size: field(&info, 3)
+                .ok_or_else(|| serde::de::Error::missing_field("MIN_ORDER_SIZE"))?,
+            max_order_size: field(&info, 4)
+                .ok_or_else(|| serde::de::Error::missing_field("MAX_ORDER_SIZE"))?,
+            initial_margin: field(&info, 8),
+            min_margin: field(&info, 9),
+            pair,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ConfPairInfoResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [pairs] = <[Vec<PairInfo>; 1]>::deserialize(deserializer)?;
+        Ok(Self { pairs })
+    }
+}
@@ -28,7 +28,7 @@ impl<'a> Endpoint for DerivativesStatus<'a> {
         String::from("v2/status/deriv")
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push("keys", self.keys.as_query_string());
         params
@@ -59,6 +59,7 @@ impl<'de> Deserialize<'de> for DerivativeStatusResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct DerivativeStatusRawResp(
             String,
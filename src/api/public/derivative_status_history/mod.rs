@@ -3,7 +3,7 @@ use http::Method;
 use serde::Deserialize;
 
 use crate::api::{
-    common::{PlaceHolder, Sort},
+    common::{PlaceHolder, Sort, Symbol},
     endpoint::Endpoint,
     params::QueryParams,
 };
@@ -11,7 +11,8 @@ use crate::api::{
 #[derive(Debug, Clone, Builder)]
 #[builder(setter(strip_option))]
 pub struct DerivativesStatusHistory<'a> {
-    key: &'a str,
+    #[builder(setter(into))]
+    key: Symbol<'a>,
     #[builder(default)]
     sort: Option<Sort>,
     #[builder(default)]
@@ -37,7 +38,7 @@ impl<'a> Endpoint for DerivativesStatusHistory<'a> {
         format!("v2/status/deriv/{}/hist", self.key)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("sort", self.sort.map(|sort| sort as i8))
@@ -71,6 +72,7 @@ impl<'de> Deserialize<'de> for DerivativeStatusHistoryResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct DerivativeStatusHistoryRawResp(
             u64,
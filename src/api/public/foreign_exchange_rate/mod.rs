@@ -0,0 +1,64 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::api::endpoint::Endpoint;
+
+/// https://docs.bitfinex.com/reference/rest-public-foreign-exchange-rate
+///
+/// The exchange rate from `ccy1` to `ccy2` (e.g. `BTC` to `USD`).
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct ForeignExchangeRate<'a> {
+    ccy1: &'a str,
+    ccy2: &'a str,
+}
+
+impl<'a> ForeignExchangeRate<'a> {
+    pub fn builder() -> ForeignExchangeRateBuilder<'a> {
+        ForeignExchangeRateBuilder::default()
+    }
+
+    fn json_body(&self) -> String {
+        #[derive(Debug, Serialize)]
+        pub struct JsonParams<'a> {
+            ccy1: &'a str,
+            ccy2: &'a str,
+        }
+
+        let p = JsonParams {
+            ccy1: self.ccy1,
+            ccy2: self.ccy2,
+        };
+
+        serde_json::to_string(&p).unwrap()
+    }
+}
+
+impl<'a> Endpoint for ForeignExchangeRate<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/calc/fx")
+    }
+
+    fn body(&self) -> Option<(&'static str, Vec<u8>)> {
+        Some(("application/json", self.json_body().into_bytes()))
+    }
+}
+
+#[derive(Debug)]
+pub struct ForeignExchangeRateResp {
+    pub rate: f64,
+}
+
+impl<'de> Deserialize<'de> for ForeignExchangeRateResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let [rate] = <[f64; 1]>::deserialize(deserializer)?;
+        Ok(Self { rate })
+    }
+}
@@ -2,12 +2,17 @@ use derive_builder::Builder;
 use http::Method;
 use serde::Deserialize;
 
-use crate::api::{common::PlaceHolder, endpoint::Endpoint, params::QueryParams};
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+    params::QueryParams,
+};
 
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct FundingStatistics<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     #[builder(default)]
     start: Option<u64>,
     #[builder(default)]
@@ -31,7 +36,7 @@ impl<'a> Endpoint for FundingStatistics<'a> {
         format!("v2/funding/stats/{}/hist", self.symbol)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("start", self.start)
@@ -58,6 +63,7 @@ impl<'de> Deserialize<'de> for FundingStatisticsRespItem {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct FundingStatisticsRawRespItem(
             u64,
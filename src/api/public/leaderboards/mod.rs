@@ -5,7 +5,7 @@ use http::Method;
 use serde::Deserialize;
 
 use crate::api::{
-    common::{PlaceHolder, Section, Sort, TimeFrame},
+    common::{PlaceHolder, Section, Sort, Symbol, TimeFrame},
     endpoint::Endpoint,
     params::QueryParams,
 };
@@ -34,7 +34,8 @@ impl Display for Key {
 pub struct Leaderboards<'a> {
     key: Key,
     time_frame: TimeFrame,
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     section: Section,
     #[builder(default)]
     sort: Option<Sort>,
@@ -64,7 +65,7 @@ impl<'a> Endpoint for Leaderboards<'a> {
         )
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("sort", self.sort.map(|sort| sort as i8))
@@ -84,6 +85,7 @@ pub struct LeaderBoardResp {
     pub username: String,
     pub ranking: u64,
     pub value: f64,
+    pub twitter_handle: Option<String>,
 }
 
 impl<'de> Deserialize<'de> for LeaderBoardResp {
@@ -91,6 +93,7 @@ impl<'de> Deserialize<'de> for LeaderBoardResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct LeaderBoardRawResp(
             u64,
@@ -102,7 +105,7 @@ impl<'de> Deserialize<'de> for LeaderBoardResp {
             f64,
             PlaceHolder,
             Option<u64>,
-            PlaceHolder,
+            Option<String>,
             PlaceHolder,
             PlaceHolder,
             Option<u64>,
@@ -110,14 +113,28 @@ impl<'de> Deserialize<'de> for LeaderBoardResp {
 
         impl From<LeaderBoardRawResp> for LeaderBoardResp {
             fn from(value: LeaderBoardRawResp) -> Self {
-                let LeaderBoardRawResp(mts, _, username, ranking, _, _, value, _, _, _, _, _, _) =
-                    value;
+                let LeaderBoardRawResp(
+                    mts,
+                    _,
+                    username,
+                    ranking,
+                    _,
+                    _,
+                    value,
+                    _,
+                    _,
+                    twitter_handle,
+                    _,
+                    _,
+                    _,
+                ) = value;
 
                 Self {
                     mts,
                     username,
                     ranking,
                     value,
+                    twitter_handle,
                 }
             }
         }
@@ -36,7 +36,7 @@ impl Endpoint for Liquidations {
         String::from("v2/liquidations/hist")
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("sort", self.sort.map(|sort| sort as i8))
@@ -66,6 +66,7 @@ impl<'de> Deserialize<'de> for LiquidationResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct LiquidationRawResp(
             String,
@@ -0,0 +1,76 @@
+use derive_builder::Builder;
+use http::Method;
+use serde::Deserialize;
+
+use crate::api::{common::Symbol, endpoint::Endpoint, params::QueryParams};
+
+/// https://docs.bitfinex.com/reference/rest-public-market-average-price
+///
+/// The average execution price of a trading order (e.g. `tBTCUSD`), or the average rate of a
+/// funding order (e.g. `fUSD`) taken for `period` days and capped at `rate_limit`.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct MarketAveragePrice<'a> {
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
+    amount: f64,
+    #[builder(default)]
+    period: Option<u8>,
+    #[builder(default)]
+    rate_limit: Option<f64>,
+}
+
+impl<'a> MarketAveragePrice<'a> {
+    pub fn builder() -> MarketAveragePriceBuilder<'a> {
+        MarketAveragePriceBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for MarketAveragePrice<'a> {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/calc/trade/avg")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push("symbol", self.symbol)
+            .push("amount", self.amount)
+            .push_opt("period", self.period)
+            .push_opt("rate_limit", self.rate_limit);
+        params
+    }
+}
+
+#[derive(Debug)]
+pub struct MarketAveragePriceResp {
+    /// The average price, or rate for the funding symbols.
+    pub price_avg: f64,
+    /// The amount that can be executed at that price.
+    pub amount: f64,
+}
+
+impl<'de> Deserialize<'de> for MarketAveragePriceResp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct MarketAveragePriceRawResp(f64, f64);
+
+        impl From<MarketAveragePriceRawResp> for MarketAveragePriceResp {
+            fn from(value: MarketAveragePriceRawResp) -> Self {
+                let MarketAveragePriceRawResp(price_avg, amount) = value;
+
+                Self { price_avg, amount }
+            }
+        }
+
+        let raw = MarketAveragePriceRawResp::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -1,11 +1,15 @@
 pub mod book;
 pub mod candles;
+pub mod conf;
 pub mod derivative_status;
 pub mod derivative_status_history;
+pub mod foreign_exchange_rate;
 pub mod funding_statistics;
 pub mod leaderboards;
 pub mod liquidations;
+pub mod market_average_price;
 pub mod platform_status;
+pub mod pulse;
 pub mod stats;
 pub mod ticker;
 pub mod tickers;
@@ -0,0 +1,212 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::common::PlaceHolder;
+
+/// [0]  PUID            string  Profile id
+/// [1]  MTS_CREATE      int     Creation timestamp
+/// [3]  NICKNAME        string  Nickname
+/// [5]  PICTURE         string  Picture file name
+/// [6]  TEXT            string  Biography
+/// [9]  TWITTER_HANDLE  string  Twitter handle
+/// [11] FOLLOWERS       int     Number of followers
+/// [12] FOLLOWING       int     Number of profiles followed
+/// [16] TIPPING_STATUS  int     1 when tipping is enabled
+///
+#[derive(Debug, Clone)]
+pub struct PulseProfile {
+    pub puid: String,
+    pub mts_create: u64,
+    pub nickname: String,
+    pub picture: Option<String>,
+    pub text: Option<String>,
+    pub twitter_handle: Option<String>,
+    pub followers: u64,
+    pub following: u64,
+    pub tipping_enabled: bool,
+}
+
+impl<'de> Deserialize<'de> for PulseProfile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct PulseProfileRaw(
+            String,
+            u64,
+            PlaceHolder,
+            String,
+            PlaceHolder,
+            Option<String>,
+            Option<String>,
+            PlaceHolder,
+            PlaceHolder,
+            Option<String>,
+            PlaceHolder,
+            Option<u64>,
+            Option<u64>,
+            PlaceHolder,
+            PlaceHolder,
+            PlaceHolder,
+            Option<u8>,
+        );
+
+        impl From<PulseProfileRaw> for PulseProfile {
+            fn from(value: PulseProfileRaw) -> Self {
+                let PulseProfileRaw(
+                    puid,
+                    mts_create,
+                    _,
+                    nickname,
+                    _,
+                    picture,
+                    text,
+                    _,
+                    _,
+                    twitter_handle,
+                    _,
+                    followers,
+                    following,
+                    _,
+                    _,
+                    _,
+                    tipping_status,
+                ) = value;
+
+                Self {
+                    puid,
+                    mts_create,
+                    nickname,
+                    picture,
+                    text,
+                    twitter_handle,
+                    followers: followers.unwrap_or_default(),
+                    following: following.unwrap_or_default(),
+                    tipping_enabled: tipping_status == Some(1),
+                }
+            }
+        }
+
+        let raw = PulseProfileRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+/// [0]  PID                string  Pulse id
+/// [1]  MTS                int     Creation timestamp
+/// [3]  PUID               string  Profile id of the author
+/// [5]  TITLE              string  Title
+/// [6]  CONTENT            string  Content
+/// [9]  IS_PIN             int     1 when pinned
+/// [10] IS_PUBLIC          int     1 when public
+/// [11] COMMENTS_DISABLED  int     1 when comments are disabled
+/// [12] TAGS               array   Tags
+/// [13] ATTACHMENTS        array   Attachments
+/// [14] META               array   Additional info
+/// [15] LIKES              int     Number of likes
+/// [18] PROFILE            array   Profile of the author
+/// [19] COMMENTS           int     Number of comments
+///
+/// The fields after `LIKES` are not returned when adding a pulse.
+#[derive(Debug, Clone)]
+pub struct Pulse {
+    pub pid: String,
+    pub mts: u64,
+    pub puid: String,
+    pub title: Option<String>,
+    pub content: String,
+    pub is_pin: bool,
+    pub is_public: bool,
+    pub comments_disabled: bool,
+    pub tags: Vec<String>,
+    pub attachments: Vec<String>,
+    pub meta: Option<Value>,
+    pub likes: u64,
+    pub profile: Option<PulseProfile>,
+    pub comments: u64,
+}
+
+impl<'de> Deserialize<'de> for Pulse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct PulseRaw(
+            String,
+            u64,
+            PlaceHolder,
+            String,
+            PlaceHolder,
+            Option<String>,
+            String,
+            PlaceHolder,
+            PlaceHolder,
+            Option<u8>,
+            Option<u8>,
+            Option<u8>,
+            Option<Vec<String>>,
+            Option<Vec<String>>,
+            Option<Value>,
+            Option<u64>,
+            #[serde(default)] PlaceHolder,
+            #[serde(default)] PlaceHolder,
+            #[serde(default)] Option<Vec<PulseProfile>>,
+            #[serde(default)] Option<u64>,
+            #[serde(default)] PlaceHolder,
+            #[serde(default)] PlaceHolder,
+        );
+
+        impl From<PulseRaw> for Pulse {
+            fn from(value: PulseRaw) -> Self {
+                let PulseRaw(
+                    pid,
+                    mts,
+                    _,
+                    puid,
+                    _,
+                    title,
+                    content,
+                    _,
+                    _,
+                    is_pin,
+                    is_public,
+                    comments_disabled,
+                    tags,
+                    attachments,
+                    meta,
+                    likes,
+                    _,
+                    _,
+                    profile,
+                    comments,
+                    _,
+                    _,
+                ) = value;
+
+                Self {
+                    pid,
+                    mts,
+                    puid,
+                    title,
+                    content,
+                    is_pin: is_pin == Some(1),
+                    is_public: is_public == Some(1),
+                    comments_disabled: comments_disabled == Some(1),
+                    tags: tags.unwrap_or_default(),
+                    attachments: attachments.unwrap_or_default(),
+                    meta,
+                    likes: likes.unwrap_or_default(),
+                    profile: profile.and_then(|profiles| profiles.into_iter().next()),
+                    comments: comments.unwrap_or_default(),
+                }
+            }
+        }
+
+        let raw = PulseRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod common;
+pub mod pulse_history;
+pub mod pulse_profile;
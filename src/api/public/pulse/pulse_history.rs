@@ -0,0 +1,42 @@
+use derive_builder::Builder;
+use http::Method;
+
+use crate::api::{endpoint::Endpoint, params::QueryParams};
+
+use super::common::Pulse;
+
+/// https://docs.bitfinex.com/reference/rest-public-pulse-hist
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct PulseHistory {
+    #[builder(default)]
+    end: Option<u64>,
+    #[builder(default)]
+    limit: Option<u64>,
+}
+
+impl PulseHistory {
+    pub fn builder() -> PulseHistoryBuilder {
+        PulseHistoryBuilder::default()
+    }
+}
+
+impl Endpoint for PulseHistory {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        String::from("v2/pulse/hist")
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params
+            .push_opt("end", self.end)
+            .push_opt("limit", self.limit);
+        params
+    }
+}
+
+pub type PulseHistoryResp = Vec<Pulse>;
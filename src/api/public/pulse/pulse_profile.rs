@@ -0,0 +1,30 @@
+use derive_builder::Builder;
+use http::Method;
+
+use crate::api::endpoint::Endpoint;
+
+use super::common::PulseProfile;
+
+/// https://docs.bitfinex.com/reference/rest-public-pulse-profile
+#[derive(Debug, Clone, Copy, Builder)]
+pub struct PulseProfileDetails<'a> {
+    nickname: &'a str,
+}
+
+impl<'a> PulseProfileDetails<'a> {
+    pub fn builder() -> PulseProfileDetailsBuilder<'a> {
+        PulseProfileDetailsBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for PulseProfileDetails<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> String {
+        format!("v2/pulse/profile/{}", self.nickname)
+    }
+}
+
+pub type PulseProfileDetailsResp = PulseProfile;
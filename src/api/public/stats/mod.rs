@@ -5,7 +5,7 @@ use http::Method;
 use serde::Deserialize;
 
 use crate::api::{
-    common::{Section, Sort},
+    common::{Section, Sort, Symbol},
     endpoint::Endpoint,
     params::QueryParams,
 };
@@ -44,14 +44,14 @@ impl Display for Side {
 
 #[derive(Debug, Clone, Copy)]
 pub enum KeyArgs<'a> {
-    PosSize { sym: &'a str, side: Side },
-    FundingSize { sym: &'a str },
-    CreditsSize { sym: &'a str },
-    CreditsSizeSym { sym: &'a str, pair: &'a str },
+    PosSize { sym: Symbol<'a>, side: Side },
+    FundingSize { sym: Symbol<'a> },
+    CreditsSize { sym: Symbol<'a> },
+    CreditsSizeSym { sym: Symbol<'a>, pair: Symbol<'a> },
     VolOneDay { platform: &'a str },
     VolSevenDay { platform: &'a str },
     VolThirtyDay { platform: &'a str },
-    Vwamp { sym: &'a str },
+    Vwamp { sym: Symbol<'a> },
 }
 
 impl<'a> Display for KeyArgs<'a> {
@@ -101,7 +101,7 @@ impl<'a> Endpoint for Stats<'a> {
         format!("v2/stats1/{}/{}", self.key_args, self.section)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("sort", self.sort.map(|sort| sort as i8))
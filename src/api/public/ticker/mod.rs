@@ -2,11 +2,15 @@ use derive_builder::Builder;
 use http::Method;
 use serde::Deserialize;
 
-use crate::api::{common::PlaceHolder, endpoint::Endpoint};
+use crate::api::{
+    common::{PlaceHolder, Symbol},
+    endpoint::Endpoint,
+};
 
 #[derive(Debug, Clone, Copy, Builder)]
 pub struct Ticker<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
 }
 
 impl<'a> Ticker<'a> {
@@ -62,6 +66,7 @@ impl<'de> Deserialize<'de> for TickerResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         #[serde(untagged)]
         enum TickerRawResp {
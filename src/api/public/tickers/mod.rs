@@ -28,7 +28,7 @@ impl<'a> Endpoint for Tickers<'a> {
         String::from("v2/tickers")
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params.push("symbols", self.symbols.as_query_string());
         params
@@ -76,6 +76,7 @@ impl<'de> Deserialize<'de> for TickerResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         #[serde(untagged)]
         enum TickersRawResp {
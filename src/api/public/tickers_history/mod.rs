@@ -35,7 +35,7 @@ impl<'a> Endpoint for TickersHistory<'a> {
         "v2/tickers/hist".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push("symbols", self.symbols.as_query_string())
@@ -61,6 +61,7 @@ impl<'de> Deserialize<'de> for TickerHistoryResp {
     where
         D: serde::Deserializer<'de>,
     {
+        #[allow(dead_code)]
         #[derive(Debug, Deserialize)]
         struct TickerHistoryRawResp(
             String,
@@ -2,12 +2,17 @@ use derive_builder::Builder;
 use http::Method;
 use serde::Deserialize;
 
-use crate::api::{common::Sort, endpoint::Endpoint, params::QueryParams};
+use crate::api::{
+    common::{Sort, Symbol},
+    endpoint::Endpoint,
+    params::QueryParams,
+};
 
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Trades<'a> {
-    symbol: &'a str,
+    #[builder(setter(into))]
+    symbol: Symbol<'a>,
     #[builder(default)]
     limit: Option<u16>,
     #[builder(default)]
@@ -33,7 +38,7 @@ impl<'a> Endpoint for Trades<'a> {
         format!("v2/trades/{}/hist", self.symbol)
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
         params
             .push_opt("limit", self.limit)
@@ -1,31 +1,47 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{io, sync::Arc};
 
 use hmac::{Hmac, Mac};
 use http::{HeaderMap, HeaderValue};
+use serde_json::{json, Value};
 use sha2::Sha384;
 
+use crate::nonce::{AtomicNonce, NonceSource};
+
 #[derive(Debug)]
 pub struct Auth {
     api_key: String,
     secret_key: String,
+    nonce_source: Arc<dyn NonceSource>,
 }
 
 impl Auth {
+    /// The nonces are generated by the [`AtomicNonce`] shared by all the `Auth` created with `api_key`.
     pub fn new(api_key: String, secret_key: String) -> Self {
+        let nonce_source = AtomicNonce::shared(&api_key);
+
         Self {
             api_key,
             secret_key,
+            nonce_source,
         }
     }
 
+    /// Replaces the source of the nonces, e.g. with a [`FileNonce`](crate::nonce::FileNonce).
+    pub fn with_nonce_source(mut self, nonce_source: Arc<dyn NonceSource>) -> Self {
+        self.nonce_source = nonce_source;
+        self
+    }
+
     /// Adds the appropriate headers to perform authenticated calls.
-    pub fn set_headers(&self, headers: &mut HeaderMap<HeaderValue>, path: &str, body: &[u8]) {
-        let nonce = self.generate_nonce();
+    pub fn set_headers(
+        &self,
+        headers: &mut HeaderMap<HeaderValue>,
+        path: &str,
+        body: &[u8],
+    ) -> io::Result<()> {
+        let nonce = self.generate_nonce()?;
         let signature_payload = format!("/api/{path}{nonce}{}", std::str::from_utf8(body).unwrap());
-
-        let mut mac = Hmac::<Sha384>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-        mac.update(signature_payload.as_bytes());
-        let signature = hex::encode(mac.finalize().into_bytes());
+        let signature = self.sign(&signature_payload);
 
         let nonce_header_value = HeaderValue::from(nonce);
         let api_key_header_value = HeaderValue::from_str(&self.api_key).unwrap();
@@ -34,14 +50,32 @@ impl Auth {
         headers.insert("bfx-nonce", nonce_header_value);
         headers.insert("bfx-apikey", api_key_header_value);
         headers.insert("bfx-signature", signature_header_value);
+
+        Ok(())
+    }
+
+    /// Builds the `auth` event to send to authenticate a WebSocket connection.
+    pub fn ws_auth_message(&self) -> io::Result<Value> {
+        let nonce = self.generate_nonce()?;
+        let auth_payload = format!("AUTH{nonce}");
+        let signature = self.sign(&auth_payload);
+
+        Ok(json!({
+            "event": "auth",
+            "apiKey": self.api_key,
+            "authSig": signature,
+            "authNonce": nonce,
+            "authPayload": auth_payload,
+        }))
     }
 
-    fn generate_nonce(&self) -> u64 {
-        let start = SystemTime::now();
-        let since_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-        let timestamp =
-            since_epoch.as_secs() * 1000 + since_epoch.subsec_nanos() as u64 / 1_000_000;
+    fn sign(&self, payload: &str) -> String {
+        let mut mac = Hmac::<Sha384>::new_from_slice(self.secret_key.as_bytes()).unwrap();
+        mac.update(payload.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
 
-        timestamp + 1
+    fn generate_nonce(&self) -> io::Result<u64> {
+        self.nonce_source.next_nonce()
     }
 }
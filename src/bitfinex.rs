@@ -1,15 +1,28 @@
+#[cfg(feature = "blocking")]
+use std::thread;
+use std::{io, sync::Arc, time::Duration};
+
 use async_trait::async_trait;
 use bytes::Bytes;
-use http::{request::Builder as RequestBuilder, Response};
+use http::{
+    header::USER_AGENT, request::Builder as RequestBuilder, request::Parts, HeaderMap, HeaderName,
+    HeaderValue, Request, Response,
+};
+#[cfg(feature = "blocking")]
 use reqwest::blocking::Client as ReqClient;
-use reqwest::Client as ReqAsyncClient;
+use reqwest::{Client as ReqAsyncClient, Proxy};
 use thiserror::Error;
 use url::Url;
 
+#[cfg(feature = "blocking")]
+use crate::api::client::Client;
 use crate::{
-    api::client::{AsyncClient, Client, RestClient},
+    api::client::{AsyncClient, RestClient},
     api::error::ApiError,
     auth::Auth,
+    nonce::NonceSource,
+    rate_limit::{EndpointPath, RateLimitExceeded, RateLimiter},
+    retry::{ErrorClass, Idempotent, RetryPolicy},
 };
 
 #[derive(Debug, Error)]
@@ -25,11 +38,272 @@ pub enum RestError {
         #[from]
         source: reqwest::Error,
     },
+
+    #[error("{}", source)]
+    RateLimited {
+        #[from]
+        source: RateLimitExceeded,
+    },
+
+    #[error("Could not generate a nonce: {}", source)]
+    Nonce {
+        #[from]
+        source: io::Error,
+    },
+}
+
+impl RestError {
+    /// Classifies the error for the [`RetryPolicy`].
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            RestError::Communication { source } if source.is_connect() => ErrorClass::Connect,
+            RestError::Communication { source }
+                if source.is_timeout() || source.is_request() || source.is_body() =>
+            {
+                ErrorClass::Communication
+            }
+            _ => ErrorClass::Permanent,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum BuildError {
+    #[error("Invalid base URL: {}", source)]
+    Url {
+        #[from]
+        source: url::ParseError,
+    },
+
+    #[error("Invalid header: {}", source)]
+    Header {
+        #[from]
+        source: http::Error,
+    },
+
+    #[error("Failed to build the HTTP client: {}", source)]
+    Client {
+        #[from]
+        source: reqwest::Error,
+    },
 }
 
 const PUB_API_URL: &str = "https://api-pub.bitfinex.com";
 const AUTH_API_URL: &str = "https://api.bitfinex.com";
 
+/// Configures a [`Bitfinex`] or an [`AsyncBitfinex`] client.
+///
+/// The HTTP options (timeouts, proxy, headers, user agent and pool size) are ignored when a pre-configured
+/// `reqwest` client is provided.
+#[derive(Debug, Clone, Default)]
+pub struct BitfinexBuilder {
+    pub_rest_url: Option<String>,
+    authenticated_rest_url: Option<String>,
+    credentials: Option<(String, String)>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    headers: Vec<(String, String)>,
+    user_agent: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+    client: Option<ReqAsyncClient>,
+    #[cfg(feature = "blocking")]
+    blocking_client: Option<ReqClient>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    nonce_source: Option<Arc<dyn NonceSource>>,
+}
+
+impl BitfinexBuilder {
+    /// The base URL of the public endpoints, defaults to `https://api-pub.bitfinex.com`.
+    ///
+    /// The URL may have a path (e.g. a gateway at `https://gateway.local/bitfinex`), the endpoint paths are
+    /// appended to it and the rate limits still apply.
+    pub fn pub_rest_url(&mut self, url: &str) -> &mut Self {
+        self.pub_rest_url = Some(url.to_string());
+        self
+    }
+
+    /// The base URL of the authenticated endpoints, defaults to `https://api.bitfinex.com`.
+    ///
+    /// As for [`pub_rest_url`](Self::pub_rest_url), the URL may have a path.
+    pub fn authenticated_rest_url(&mut self, url: &str) -> &mut Self {
+        self.authenticated_rest_url = Some(url.to_string());
+        self
+    }
+
+    /// The credentials used to sign the authenticated requests.
+    pub fn auth(&mut self, api_key: &str, secret_key: &str) -> &mut Self {
+        self.credentials = Some((api_key.to_string(), secret_key.to_string()));
+        self
+    }
+
+    /// The timeout to establish a connection.
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// The timeout of a whole request, from connecting to reading the response body.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn proxy(&mut self, proxy: Proxy) -> &mut Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Adds a header sent with every request.
+    pub fn header(&mut self, name: &str, value: &str) -> &mut Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// The maximum number of idle connections kept per host.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) -> &mut Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// A pre-configured client used by [`AsyncBitfinex`].
+    pub fn client(&mut self, client: ReqAsyncClient) -> &mut Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// A pre-configured client used by [`Bitfinex`].
+    #[cfg(feature = "blocking")]
+    pub fn blocking_client(&mut self, client: ReqClient) -> &mut Self {
+        self.blocking_client = Some(client);
+        self
+    }
+
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub fn rate_limiter(&mut self, rate_limiter: RateLimiter) -> &mut Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    pub fn nonce_source(&mut self, nonce_source: Arc<dyn NonceSource>) -> &mut Self {
+        self.nonce_source = Some(nonce_source);
+        self
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn build(&self) -> Result<Bitfinex, BuildError> {
+        let client = match &self.blocking_client {
+            Some(client) => client.clone(),
+            None => {
+                let mut builder = ReqClient::builder().default_headers(self.default_headers()?);
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(proxy) = &self.proxy {
+                    builder = builder.proxy(proxy.clone());
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(Bitfinex {
+            client,
+            pub_rest_url: base_url(self.pub_rest_url.as_deref(), PUB_API_URL)?,
+            authenticated_rest_url: base_url(self.authenticated_rest_url.as_deref(), AUTH_API_URL)?,
+            auth: self.build_auth(),
+            retry_policy: self.retry_policy.clone().unwrap_or_else(RetryPolicy::never),
+            rate_limiter: self.rate_limiter.clone().unwrap_or_default(),
+        })
+    }
+
+    pub fn build_async(&self) -> Result<AsyncBitfinex, BuildError> {
+        let client = match &self.client {
+            Some(client) => client.clone(),
+            None => {
+                let mut builder =
+                    ReqAsyncClient::builder().default_headers(self.default_headers()?);
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(proxy) = &self.proxy {
+                    builder = builder.proxy(proxy.clone());
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(AsyncBitfinex {
+            client,
+            pub_rest_url: base_url(self.pub_rest_url.as_deref(), PUB_API_URL)?,
+            authenticated_rest_url: base_url(self.authenticated_rest_url.as_deref(), AUTH_API_URL)?,
+            auth: self.build_auth(),
+            retry_policy: self.retry_policy.clone().unwrap_or_else(RetryPolicy::never),
+            rate_limiter: self.rate_limiter.clone().unwrap_or_default(),
+        })
+    }
+
+    fn default_headers(&self) -> Result<HeaderMap, BuildError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::try_from(name).map_err(http::Error::from)?,
+                HeaderValue::try_from(value).map_err(http::Error::from)?,
+            );
+        }
+        if let Some(user_agent) = &self.user_agent {
+            headers.insert(
+                USER_AGENT,
+                HeaderValue::try_from(user_agent).map_err(http::Error::from)?,
+            );
+        }
+
+        Ok(headers)
+    }
+
+    fn build_auth(&self) -> Option<Auth> {
+        let (api_key, secret_key) = self.credentials.clone()?;
+        let auth = Auth::new(api_key, secret_key);
+
+        Some(match &self.nonce_source {
+            Some(nonce_source) => auth.with_nonce_source(nonce_source.clone()),
+            None => auth,
+        })
+    }
+}
+
+/// Parses a base URL, adding the trailing slash needed to join the endpoint paths to it.
+fn base_url(url: Option<&str>, default: &str) -> Result<Url, BuildError> {
+    let mut url = Url::parse(url.unwrap_or(default))?;
+    if !url.path().ends_with('/') {
+        url.set_path(&format!("{}/", url.path()));
+    }
+
+    Ok(url)
+}
+
+#[cfg(feature = "blocking")]
 #[derive(Debug)]
 pub struct Bitfinex {
     /// The client to use for API calls.
@@ -43,6 +317,12 @@ pub struct Bitfinex {
 
     /// The authentication information to use.
     auth: Option<Auth>,
+
+    /// How failed requests are retried.
+    retry_policy: RetryPolicy,
+
+    /// The limiter applied before each request.
+    rate_limiter: RateLimiter,
 }
 
 #[derive(Debug)]
@@ -58,28 +338,49 @@ pub struct AsyncBitfinex {
 
     /// The authentication information to use.
     auth: Option<Auth>,
+
+    /// How failed requests are retried.
+    retry_policy: RetryPolicy,
+
+    /// The limiter applied before each request.
+    rate_limiter: RateLimiter,
 }
 
+#[cfg(feature = "blocking")]
 impl Bitfinex {
+    pub fn builder() -> BitfinexBuilder {
+        BitfinexBuilder::default()
+    }
+
     pub fn new() -> Self {
-        Self {
-            client: ReqClient::new(),
-            pub_rest_url: Url::parse(PUB_API_URL).unwrap(),
-            authenticated_rest_url: Url::parse(AUTH_API_URL).unwrap(),
-            auth: None,
-        }
+        Self::builder().build().unwrap()
     }
 
     pub fn new_auth(api_key: &str, secret_key: &str) -> Self {
-        Self {
-            client: ReqClient::new(),
-            pub_rest_url: Url::parse(PUB_API_URL).unwrap(),
-            authenticated_rest_url: Url::parse(AUTH_API_URL).unwrap(),
-            auth: Some(Auth::new(api_key.to_string(), secret_key.to_string())),
-        }
+        Self::builder().auth(api_key, secret_key).build().unwrap()
+    }
+
+    /// Sets the [`RetryPolicy`], a single attempt is made by default.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Replaces the default [`RateLimiter`], which waits for the Bitfinex limits to allow each request.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Replaces the source of the nonces of the authenticated requests, which is by default shared by all the
+    /// clients created with the same API key.
+    pub fn with_nonce_source(mut self, nonce_source: Arc<dyn NonceSource>) -> Self {
+        self.auth = self.auth.map(|auth| auth.with_nonce_source(nonce_source));
+        self
     }
 }
 
+#[cfg(feature = "blocking")]
 impl Default for Bitfinex {
     fn default() -> Self {
         Self::new()
@@ -87,22 +388,38 @@ impl Default for Bitfinex {
 }
 
 impl AsyncBitfinex {
+    pub fn builder() -> BitfinexBuilder {
+        BitfinexBuilder::default()
+    }
+
     pub fn new() -> Self {
-        Self {
-            client: ReqAsyncClient::new(),
-            pub_rest_url: Url::parse(PUB_API_URL).unwrap(),
-            authenticated_rest_url: Url::parse(AUTH_API_URL).unwrap(),
-            auth: None,
-        }
+        Self::builder().build_async().unwrap()
     }
 
     pub fn new_auth(api_key: &str, secret_key: &str) -> Self {
-        Self {
-            client: ReqAsyncClient::new(),
-            pub_rest_url: Url::parse(PUB_API_URL).unwrap(),
-            authenticated_rest_url: Url::parse(AUTH_API_URL).unwrap(),
-            auth: Some(Auth::new(api_key.to_string(), secret_key.to_string())),
-        }
+        Self::builder()
+            .auth(api_key, secret_key)
+            .build_async()
+            .unwrap()
+    }
+
+    /// Sets the [`RetryPolicy`], a single attempt is made by default.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Replaces the default [`RateLimiter`], which waits for the Bitfinex limits to allow each request.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Replaces the source of the nonces of the authenticated requests, which is by default shared by all the
+    /// clients created with the same API key.
+    pub fn with_nonce_source(mut self, nonce_source: Arc<dyn NonceSource>) -> Self {
+        self.auth = self.auth.map(|auth| auth.with_nonce_source(nonce_source));
+        self
     }
 }
 
@@ -112,6 +429,7 @@ impl Default for AsyncBitfinex {
     }
 }
 
+#[cfg(feature = "blocking")]
 impl RestClient for Bitfinex {
     type Error = RestError;
 
@@ -144,43 +462,110 @@ impl RestClient for AsyncBitfinex {
     }
 }
 
+#[cfg(feature = "blocking")]
+impl Bitfinex {
+    /// Makes a single attempt.
+    fn send(
+        &self,
+        mut request_builder: RequestBuilder,
+        body: Vec<u8>,
+        path_to_sign: Option<&str>,
+    ) -> Result<Response<Bytes>, RestError> {
+        // If a path to sign has been provided, compute and adds the necessary authorization headers to the request.
+        if let (Some(path_to_sign), Some(auth)) = (path_to_sign, &self.auth) {
+            auth.set_headers(request_builder.headers_mut().unwrap(), path_to_sign, &body)?;
+        }
+
+        // Build the request.
+        let http_request = request_builder.body(body)?;
+
+        // Convert it to a reqwest::Request type and send it.
+        let request = http_request.try_into()?;
+        let rsp = self.client.execute(request)?;
+
+        // Build the HTTP response.
+        let mut http_rsp = Response::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+
+        // Insert any headers in the reponses.
+        if let Some(headers) = http_rsp.headers_mut() {
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+        }
+
+        // Return the reponse as raw bytes.
+        Ok(http_rsp.body(rsp.bytes()?)?)
+    }
+}
+
+#[cfg(feature = "blocking")]
 impl Client for Bitfinex {
     fn rest(
         &self,
-        mut request_builder: RequestBuilder,
+        request_builder: RequestBuilder,
         body: Vec<u8>,
         path_to_sign: Option<String>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
-        let call = || {
-            // If a path to sign has been provided, compute and adds the necessary authorization headers to the request.
-            if let (Some(path_to_sign), Some(auth)) = (path_to_sign, &self.auth) {
-                auth.set_headers(request_builder.headers_mut().unwrap(), &path_to_sign, &body);
+        let (parts, idempotent, endpoint_path) = split_request(request_builder)?;
+
+        let mut attempt = 1;
+        loop {
+            let delay = acquire(&self.rate_limiter, endpoint_path.as_deref())?;
+            if !delay.is_zero() {
+                thread::sleep(delay);
             }
 
-            // Build the request.
-            let http_request = request_builder.body(body)?;
+            let result = self.send(
+                rebuild_request(&parts),
+                body.clone(),
+                path_to_sign.as_deref(),
+            );
 
-            // Convert it to a reqwest::Request type and send it.
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request)?;
+            match retry_delay(&self.retry_policy, attempt, idempotent, &result) {
+                Some(delay) => thread::sleep(delay),
+                None => return result.map_err(ApiError::client),
+            }
+            attempt += 1;
+        }
+    }
+}
 
-            // Build the HTTP response.
-            let mut http_rsp = Response::builder()
-                .status(rsp.status())
-                .version(rsp.version());
+impl AsyncBitfinex {
+    /// Makes a single attempt.
+    async fn send(
+        &self,
+        mut request_builder: RequestBuilder,
+        body: Vec<u8>,
+        path_to_sign: Option<&str>,
+    ) -> Result<Response<Bytes>, RestError> {
+        // If a path to sign has been provided, compute and adds the necessary authorization headers to the request.
+        if let (Some(path_to_sign), Some(auth)) = (path_to_sign, &self.auth) {
+            auth.set_headers(request_builder.headers_mut().unwrap(), path_to_sign, &body)?;
+        }
 
-            // Insert any headers in the reponses.
-            if let Some(headers) = http_rsp.headers_mut() {
-                for (key, value) in rsp.headers() {
-                    headers.insert(key, value.clone());
-                }
-            }
+        // Build the request.
+        let http_request = request_builder.body(body)?;
 
-            // Return the reponse as raw bytes.
-            Ok(http_rsp.body(rsp.bytes()?)?)
-        };
+        // Convert it to a reqwest::Request type and send it.
+        let request = http_request.try_into()?;
+        let rsp = self.client.execute(request).await?;
 
-        call().map_err(ApiError::client)
+        // Build the HTTP response.
+        let mut http_rsp = Response::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+
+        // Insert any headers in the reponses.
+        if let Some(headers) = http_rsp.headers_mut() {
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+        }
+
+        // Return the reponse as raw bytes.
+        Ok(http_rsp.body(rsp.bytes().await?)?)
     }
 }
 
@@ -188,39 +573,92 @@ impl Client for Bitfinex {
 impl AsyncClient for AsyncBitfinex {
     async fn rest_async(
         &self,
-        mut request_builder: RequestBuilder,
+        request_builder: RequestBuilder,
         body: Vec<u8>,
         path_to_sign: Option<String>,
     ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
-        let call = || async {
-            // If a path to sign has been provided, compute and adds the necessary authorization headers to the request.
-            if let (Some(path_to_sign), Some(auth)) = (path_to_sign, &self.auth) {
-                auth.set_headers(request_builder.headers_mut().unwrap(), &path_to_sign, &body);
-            }
+        let (parts, idempotent, endpoint_path) = split_request(request_builder)?;
 
-            // Build the request.
-            let http_request = request_builder.body(body)?;
+        let mut attempt = 1;
+        loop {
+            let delay = acquire(&self.rate_limiter, endpoint_path.as_deref())?;
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
 
-            // Convert it to a reqwest::Request type and send it.
-            let request = http_request.try_into()?;
-            let rsp = self.client.execute(request).await?;
+            let result = self
+                .send(
+                    rebuild_request(&parts),
+                    body.clone(),
+                    path_to_sign.as_deref(),
+                )
+                .await;
+
+            match retry_delay(&self.retry_policy, attempt, idempotent, &result) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return result.map_err(ApiError::client),
+            }
+            attempt += 1;
+        }
+    }
+}
 
-            // Build the HTTP response.
-            let mut http_rsp = Response::builder()
-                .status(rsp.status())
-                .version(rsp.version());
+/// Extracts the parts of the request to send, and whether it is idempotent, so it can be rebuilt for each attempt.
+fn split_request(
+    request_builder: RequestBuilder,
+) -> Result<(Parts, bool, Option<String>), ApiError<RestError>> {
+    let extensions = request_builder.extensions_ref();
+    let idempotent = extensions
+        .and_then(|extensions| extensions.get::<Idempotent>())
+        .is_some_and(|idempotent| idempotent.0);
+    let endpoint_path = extensions
+        .and_then(|extensions| extensions.get::<EndpointPath>())
+        .map(|endpoint_path| endpoint_path.0.clone());
+
+    let (parts, _) = request_builder
+        .body(())
+        .map_err(|source| ApiError::client(source.into()))?
+        .into_parts();
+
+    Ok((parts, idempotent, endpoint_path))
+}
 
-            // Insert any headers in the reponses.
-            if let Some(headers) = http_rsp.headers_mut() {
-                for (key, value) in rsp.headers() {
-                    headers.insert(key, value.clone());
-                }
-            }
+/// Takes a rate limiter token for the endpoint of the request, returns how long to wait before sending it.
+fn acquire(
+    rate_limiter: &RateLimiter,
+    endpoint_path: Option<&str>,
+) -> Result<Duration, ApiError<RestError>> {
+    match endpoint_path {
+        Some(endpoint_path) => rate_limiter
+            .acquire(endpoint_path)
+            .map_err(|source| ApiError::client(source.into())),
+        None => Ok(Duration::ZERO),
+    }
+}
 
-            // Return the reponse as raw bytes.
-            Ok(http_rsp.body(rsp.bytes().await?)?)
-        };
+fn rebuild_request(parts: &Parts) -> RequestBuilder {
+    let mut request_builder = Request::builder()
+        .method(parts.method.clone())
+        .uri(parts.uri.clone())
+        .version(parts.version);
 
-        call().await.map_err(ApiError::client)
+    if let Some(headers) = request_builder.headers_mut() {
+        headers.extend(parts.headers.clone());
     }
+
+    request_builder
+}
+
+fn retry_delay(
+    retry_policy: &RetryPolicy,
+    attempt: u32,
+    idempotent: bool,
+    result: &Result<Response<Bytes>, RestError>,
+) -> Option<Duration> {
+    let class = match result {
+        Ok(rsp) => ErrorClass::from_response(rsp)?,
+        Err(e) => e.class(),
+    };
+
+    retry_policy.delay(attempt, class, idempotent)
 }
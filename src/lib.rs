@@ -1,3 +1,8 @@
 pub mod api;
 pub mod auth;
 pub mod bitfinex;
+pub mod mock;
+pub mod nonce;
+pub mod rate_limit;
+pub mod retry;
+pub mod ws;
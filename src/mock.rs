@@ -0,0 +1,211 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{request::Builder as RequestBuilder, Method, Response, StatusCode};
+use serde_json::Value;
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{
+    client::{AsyncClient, Client, RestClient},
+    error::ApiError,
+};
+
+const MOCK_API_URL: &str = "https://mock.bitfinex.invalid/";
+
+#[derive(Debug, Error)]
+pub enum MockError {
+    #[error("HTTP error: {}", source)]
+    Http {
+        #[from]
+        source: http::Error,
+    },
+
+    #[error("No mocked response for {} {}", method, endpoint)]
+    NoMatch { method: Method, endpoint: String },
+}
+
+/// A canned response, returned for the requests matching its method, endpoint and, when set,
+/// query parameters and JSON body.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    method: Method,
+    endpoint: String,
+    params: Option<Vec<(String, String)>>,
+    body: Option<Value>,
+    status: StatusCode,
+    data: Vec<u8>,
+}
+
+impl MockResponse {
+    /// A `200 OK` response with an empty array, for any request to `endpoint` (e.g. `v2/tickers`).
+    pub fn new(method: Method, endpoint: &str) -> Self {
+        Self {
+            method,
+            endpoint: endpoint.to_string(),
+            params: None,
+            body: None,
+            status: StatusCode::OK,
+            data: b"[]".to_vec(),
+        }
+    }
+
+    /// Only matches the requests with exactly these query parameters, in any order.
+    pub fn params(mut self, params: &[(&str, &str)]) -> Self {
+        let mut params = params
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        params.sort();
+
+        self.params = Some(params);
+        self
+    }
+
+    /// Only matches the requests with this JSON body.
+    pub fn body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// The data of the response, e.g. a fixture.
+    pub fn data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        self.data = data.into();
+        self
+    }
+
+    fn matches(&self, request: &MockRequest) -> bool {
+        self.method == request.method
+            && self.endpoint == request.endpoint
+            && self
+                .params
+                .as_ref()
+                .is_none_or(|params| *params == request.params)
+            && self.body.as_ref().is_none_or(|body| {
+                serde_json::from_slice::<Value>(&request.body).is_ok_and(|v| v == *body)
+            })
+    }
+}
+
+/// A request received by a [`MockClient`].
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: Method,
+    /// The endpoint path, e.g. `v2/tickers`.
+    pub endpoint: String,
+    /// The sorted query parameters.
+    pub params: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// Whether the request would have been signed.
+    pub is_authenticated: bool,
+}
+
+/// An in-process client answering the requests with [`MockResponse`]s, for tests.
+///
+/// The responses are tried in the order they were added, and can be returned any number of times.
+#[derive(Debug, Default)]
+pub struct MockClient {
+    responses: Vec<MockResponse>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, response: MockResponse) -> Self {
+        self.responses.push(response);
+        self
+    }
+
+    /// The requests received so far.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(
+        &self,
+        request_builder: RequestBuilder,
+        body: Vec<u8>,
+        path_to_sign: Option<String>,
+    ) -> Result<Response<Bytes>, MockError> {
+        let request = request_builder.body(body)?;
+
+        let mut params = request
+            .uri()
+            .query()
+            .map(|query| {
+                url::form_urlencoded::parse(query.as_bytes())
+                    .into_owned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        params.sort();
+
+        let request = MockRequest {
+            method: request.method().clone(),
+            endpoint: request.uri().path().trim_start_matches('/').to_string(),
+            params,
+            body: request.into_body(),
+            is_authenticated: path_to_sign.is_some(),
+        };
+        self.requests.lock().unwrap().push(request.clone());
+
+        let response = self
+            .responses
+            .iter()
+            .find(|response| response.matches(&request))
+            .ok_or(MockError::NoMatch {
+                method: request.method,
+                endpoint: request.endpoint,
+            })?;
+
+        Ok(Response::builder()
+            .status(response.status)
+            .body(Bytes::from(response.data.clone()))?)
+    }
+}
+
+impl RestClient for MockClient {
+    type Error = MockError;
+
+    fn rest_endpoint(
+        &self,
+        endpoint: &str,
+        _is_authenticated: bool,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(MOCK_API_URL)?.join(endpoint)?)
+    }
+}
+
+impl Client for MockClient {
+    fn rest(
+        &self,
+        request_builder: RequestBuilder,
+        body: Vec<u8>,
+        path_to_sign: Option<String>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.respond(request_builder, body, path_to_sign)
+            .map_err(ApiError::client)
+    }
+}
+
+#[async_trait]
+impl AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request_builder: RequestBuilder,
+        body: Vec<u8>,
+        path_to_sign: Option<String>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.respond(request_builder, body, path_to_sign)
+            .map_err(ApiError::client)
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    fmt::Debug,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Generates the nonces of the authenticated requests.
+///
+/// Bitfinex rejects any nonce that is not strictly greater than the previous one used with the same API key
+/// (`nonce: small`), so a source must be shared by all the clients using a key.
+pub trait NonceSource: Debug + Send + Sync {
+    /// Returns a nonce strictly greater than all the ones previously returned, or the error that
+    /// prevented it from being recorded.
+    fn next_nonce(&self) -> io::Result<u64>;
+}
+
+/// The default [`NonceSource`]: the current timestamp in microseconds, bumped when needed to stay strictly
+/// increasing.
+#[derive(Debug, Default)]
+pub struct AtomicNonce {
+    last: AtomicU64,
+}
+
+impl AtomicNonce {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A source whose nonces are all greater than `last`.
+    pub fn starting_after(last: u64) -> Self {
+        Self {
+            last: AtomicU64::new(last),
+        }
+    }
+
+    /// The source shared by all the clients created with `api_key`, see [`Auth::new`](crate::auth::Auth::new).
+    pub fn shared(api_key: &str) -> Arc<Self> {
+        static SOURCES: OnceLock<Mutex<HashMap<String, Arc<AtomicNonce>>>> = OnceLock::new();
+
+        let mut sources = SOURCES.get_or_init(Default::default).lock().unwrap();
+        sources.entry(api_key.to_string()).or_default().clone()
+    }
+
+    fn next(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_micros() as u64;
+
+        let previous = self
+            .last
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+                Some(now.max(last + 1))
+            })
+            .unwrap();
+
+        now.max(previous + 1)
+    }
+}
+
+impl NonceSource for AtomicNonce {
+    fn next_nonce(&self) -> io::Result<u64> {
+        Ok(self.next())
+    }
+}
+
+/// A [`NonceSource`] persisting the last nonce to a file, so that a restarted process never reuses a nonce
+/// even if the clock went backward or nonces were generated ahead of it.
+///
+/// Each nonce is written to a temporary file in the same directory which then replaces the previous one, so
+/// that a crash never leaves a truncated file behind. A failure to persist a nonce fails the request using it.
+#[derive(Debug)]
+pub struct FileNonce {
+    path: PathBuf,
+    nonce: AtomicNonce,
+    file: Mutex<()>,
+}
+
+impl FileNonce {
+    /// Reads the last nonce from `path` (if it exists and isn't empty) and checks that the file can be written.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        let last = match fs::read_to_string(&path) {
+            Ok(content) if content.trim().is_empty() => 0,
+            Ok(content) => content
+                .trim()
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e),
+        };
+        persist(&path, last)?;
+
+        Ok(Self {
+            path,
+            nonce: AtomicNonce::starting_after(last),
+            file: Mutex::new(()),
+        })
+    }
+}
+
+impl NonceSource for FileNonce {
+    fn next_nonce(&self) -> io::Result<u64> {
+        // Holding the lock while writing keeps the file content increasing.
+        let _file = self.file.lock().unwrap();
+        let nonce = self.nonce.next();
+        persist(&self.path, nonce)?;
+        Ok(nonce)
+    }
+}
+
+/// Atomically replaces the content of `path` with `nonce`.
+fn persist(path: &Path, nonce: u64) -> io::Result<()> {
+    let mut tmp_path = OsString::from(path);
+    tmp_path.push(".tmp");
+
+    let mut tmp = File::create(&tmp_path)?;
+    tmp.write_all(nonce.to_string().as_bytes())?;
+    tmp.sync_all()?;
+
+    fs::rename(&tmp_path, path)
+}
@@ -0,0 +1,254 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use thiserror::Error;
+
+/// The request limits enforced by Bitfinex, keyed by endpoint path pattern.
+///
+/// A `*` segment matches any single path segment, and the first matching pattern applies.
+///
+/// https://docs.bitfinex.com/docs/requirements-and-limitations#rest-rate-limits
+const DEFAULT_LIMITS: &[(&str, u32)] = &[
+    // Public endpoints.
+    ("v2/platform/status", 30),
+    ("v2/tickers", 90),
+    ("v2/tickers/hist", 90),
+    ("v2/ticker/*", 90),
+    ("v2/trades/*/hist", 15),
+    ("v2/book/*/*", 90),
+    ("v2/stats1/*/*", 90),
+    ("v2/candles/*/*", 30),
+    ("v2/status/deriv", 90),
+    ("v2/status/deriv/*/hist", 90),
+    ("v2/liquidations/hist", 90),
+    ("v2/rankings/*/*", 90),
+    ("v2/funding/stats/*/hist", 90),
+    ("v2/conf/*", 90),
+    ("v2/calc/trade/avg", 90),
+    ("v2/calc/fx", 90),
+    ("v2/pulse/profile/*", 90),
+    ("v2/pulse/hist", 90),
+    // Authenticated endpoints.
+    ("v2/auth/r/wallets", 90),
+    ("v2/auth/r/orders", 90),
+    ("v2/auth/r/orders/hist", 45),
+    ("v2/auth/r/orders/*", 90),
+    ("v2/auth/r/trades/hist", 45),
+    ("v2/auth/r/trades/*/hist", 45),
+    ("v2/auth/r/order/*/trades", 90),
+    ("v2/auth/r/funding/offers/*", 90),
+    ("v2/auth/r/funding/loans/*", 90),
+    ("v2/auth/r/funding/credits/*", 90),
+    ("v2/auth/r/funding/offers/*/hist", 90),
+    ("v2/auth/r/funding/loans/*/hist", 90),
+    ("v2/auth/r/funding/credits/*/hist", 90),
+    ("v2/auth/r/funding/trades/hist", 90),
+    ("v2/auth/r/funding/trades/*/hist", 90),
+    ("v2/auth/r/info/funding/*", 90),
+    ("v2/auth/r/ledgers/hist", 90),
+    ("v2/auth/r/ledgers/*/hist", 90),
+    ("v2/auth/r/info/user", 90),
+    ("v2/auth/r/info/margin/*", 90),
+    ("v2/auth/r/summary", 90),
+    ("v2/auth/r/permissions", 90),
+    ("v2/auth/r/logins/hist", 90),
+    ("v2/auth/r/alerts", 90),
+    ("v2/auth/r/settings", 90),
+    ("v2/auth/r/pulse/hist", 90),
+    ("v2/auth/r/movements/hist", 90),
+    ("v2/auth/r/movements/info", 90),
+    ("v2/auth/r/movements/*/hist", 90),
+    ("v2/auth/r/deposit/address/all", 90),
+    ("v2/auth/r/positions", 90),
+    ("v2/auth/r/positions/hist", 90),
+    ("v2/auth/r/positions/snap", 90),
+    ("v2/auth/r/positions/audit", 90),
+    ("v2/auth/calc/order/avail", 90),
+    ("v2/auth/calc/deriv/collateral/limits", 90),
+    ("v2/auth/w/order/submit", 90),
+    ("v2/auth/w/order/update", 90),
+    ("v2/auth/w/order/cancel", 90),
+    ("v2/auth/w/order/multi", 90),
+    ("v2/auth/w/funding/offer/submit", 90),
+    ("v2/auth/w/funding/offer/cancel", 90),
+    ("v2/auth/w/funding/offer/cancel/all", 90),
+    ("v2/auth/w/funding/auto", 90),
+    ("v2/auth/w/funding/keep", 90),
+    ("v2/auth/w/funding/close", 90),
+    ("v2/auth/w/deriv/collateral/set", 90),
+    ("v2/auth/w/alert/set", 90),
+    ("v2/auth/w/alert/*/del", 90),
+    ("v2/auth/w/settings/set", 90),
+    ("v2/auth/w/settings/del", 90),
+    ("v2/auth/w/pulse/add", 90),
+    ("v2/auth/w/pulse/del", 90),
+    ("v2/auth/w/deposit/address", 90),
+    ("v2/auth/w/deposit/invoice", 90),
+    ("v2/auth/w/withdraw", 90),
+    ("v2/auth/w/transfer", 90),
+    ("v2/auth/w/position/claim", 90),
+    ("v2/auth/w/position/increase", 90),
+    ("v2/auth/w/position/increase/info", 90),
+];
+
+/// Carried as a request extension, the endpoint path (e.g. `v2/ticker/tBTCUSD`) the request is limited by.
+///
+/// Set by the `Query` and `AsyncQuery` implementations from [`Endpoint::endpoint`](crate::api::endpoint::Endpoint::endpoint),
+/// so that the limits apply whatever the base URL. Requests without it are not limited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointPath(pub String);
+
+/// The request was not sent because it would exceed the rate limit of its endpoint.
+#[derive(Debug, Error)]
+#[error("Rate limit of {} reached, retry after {:?}", pattern, retry_after)]
+pub struct RateLimitExceeded {
+    /// The endpoint path pattern whose limit has been reached.
+    pub pattern: String,
+
+    /// The delay after which a request can be sent.
+    pub retry_after: Duration,
+}
+
+/// What to do with a request that would exceed its endpoint limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Wait until the request can be sent.
+    Wait,
+    /// Fail with [`RateLimitExceeded`] without sending the request.
+    FailFast,
+}
+
+/// A number of requests allowed per period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub period: Duration,
+}
+
+impl RateLimit {
+    pub fn per_minute(requests: u32) -> Self {
+        Self {
+            requests,
+            period: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// A token bucket limiter keyed by endpoint path pattern, used by [`Bitfinex`](crate::bitfinex::Bitfinex) and
+/// [`AsyncBitfinex`](crate::bitfinex::AsyncBitfinex) before each request.
+///
+/// Bitfinex blocks the IP for 60 seconds once a limit is exceeded, clones share their buckets so a single
+/// limiter can be used by several clients. Endpoints matching no pattern are not limited.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    mode: RateLimitMode,
+    limits: Vec<(String, RateLimit)>,
+    buckets: Arc<Mutex<HashMap<String, Bucket>>>,
+}
+
+impl RateLimiter {
+    /// A limiter with the default limit table.
+    pub fn new(mode: RateLimitMode) -> Self {
+        Self {
+            mode,
+            limits: DEFAULT_LIMITS
+                .iter()
+                .map(|(pattern, requests)| (pattern.to_string(), RateLimit::per_minute(*requests)))
+                .collect(),
+            buckets: Default::default(),
+        }
+    }
+
+    /// A limiter that never limits requests.
+    pub fn unlimited() -> Self {
+        Self {
+            mode: RateLimitMode::Wait,
+            limits: Vec::new(),
+            buckets: Default::default(),
+        }
+    }
+
+    /// Sets the limit of the endpoints matching `pattern`, taking precedence over the existing ones.
+    pub fn with_limit(mut self, pattern: &str, limit: RateLimit) -> Self {
+        self.limits.retain(|(other, _)| other != pattern);
+        self.limits.insert(0, (pattern.to_string(), limit));
+        self
+    }
+
+    /// The limit that applies to the endpoint `path`, with its pattern.
+    pub fn limit(&self, path: &str) -> Option<(&str, RateLimit)> {
+        self.limits
+            .iter()
+            .find(|(pattern, _)| matches(pattern, path))
+            .map(|(pattern, limit)| (pattern.as_str(), *limit))
+    }
+
+    /// Takes a token for the endpoint `path`, returns how long to wait before sending the request.
+    ///
+    /// In [`RateLimitMode::Wait`] the token is reserved right away so concurrent requests are queued.
+    pub fn acquire(&self, path: &str) -> Result<Duration, RateLimitExceeded> {
+        let Some((pattern, limit)) = self.limit(path.trim_start_matches('/')) else {
+            return Ok(Duration::ZERO);
+        };
+
+        let capacity = limit.requests as f64;
+        let refill_per_sec = capacity / limit.period.as_secs_f64();
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry(pattern.to_string()).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_sec).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1. {
+            bucket.tokens -= 1.;
+            return Ok(Duration::ZERO);
+        }
+
+        let wait = Duration::from_secs_f64((1. - bucket.tokens) / refill_per_sec);
+        match self.mode {
+            RateLimitMode::Wait => {
+                bucket.tokens -= 1.;
+                Ok(wait)
+            }
+            RateLimitMode::FailFast => Err(RateLimitExceeded {
+                pattern: pattern.to_string(),
+                retry_after: wait,
+            }),
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitMode::Wait)
+    }
+}
+
+fn matches(pattern: &str, path: &str) -> bool {
+    let mut pattern = pattern.split('/');
+    let mut path = path.split('/');
+
+    loop {
+        match (pattern.next(), path.next()) {
+            (Some("*"), Some(_)) => {}
+            (Some(expected), Some(segment)) if expected == segment => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use derive_builder::Builder;
+use http::{Response, StatusCode};
+use serde_json::Value;
+
+use crate::api::error::{BitfinexError, ErrorCode};
+
+/// Carried as a request extension, tells the client whether the request can be safely sent twice.
+///
+/// Set by the `Query` and `AsyncQuery` implementations from [`Endpoint::is_idempotent`](crate::api::endpoint::Endpoint::is_idempotent).
+/// Requests without it are considered not idempotent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Idempotent(pub bool);
+
+/// The kind of failure of an attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The connection could not be established, the request never reached the server.
+    Connect,
+    /// The request failed after being sent (e.g. timeout), it may have been processed.
+    Communication,
+    /// The request has been rejected by the rate limiter (`ERR_RATE_LIMIT`).
+    RateLimit,
+    /// The request has been rejected because of its nonce (`nonce: small`).
+    Nonce,
+    /// The server failed without a Bitfinex error payload (e.g. a 502 from a proxy).
+    Server,
+    /// Any other failure, retrying won't help.
+    Permanent,
+}
+
+impl ErrorClass {
+    /// Classifies a response, returns `None` for successful ones.
+    ///
+    /// Bitfinex reports most errors with a `500` status and a `["error", CODE, MESSAGE]` payload, those are
+    /// permanent unless they are a rate limit or nonce error.
+    pub fn from_response(rsp: &Response<Bytes>) -> Option<Self> {
+        let status = rsp.status();
+        if status.is_success() {
+            return None;
+        }
+
+        let error = serde_json::from_slice::<Value>(rsp.body())
+            .ok()
+            .and_then(|v| BitfinexError::from_value(&v));
+
+        let class = match error.map(|error| error.code) {
+            _ if status == StatusCode::TOO_MANY_REQUESTS => ErrorClass::RateLimit,
+            Some(ErrorCode::RateLimit) => ErrorClass::RateLimit,
+            Some(ErrorCode::NonceSmall) => ErrorClass::Nonce,
+            None if status.is_server_error() => ErrorClass::Server,
+            _ => ErrorClass::Permanent,
+        };
+
+        Some(class)
+    }
+
+    /// Whether another attempt may succeed.
+    pub fn is_transient(self) -> bool {
+        self != ErrorClass::Permanent
+    }
+
+    /// Whether the server is known not to have processed the request, which makes it safe to send again.
+    pub fn is_unprocessed(self) -> bool {
+        matches!(
+            self,
+            ErrorClass::Connect | ErrorClass::RateLimit | ErrorClass::Nonce
+        )
+    }
+}
+
+/// Retries transient failures, and failures of non idempotent requests only when they haven't been processed.
+pub fn default_retry_on(class: ErrorClass, idempotent: bool) -> bool {
+    class.is_transient() && (idempotent || class.is_unprocessed())
+}
+
+/// How failed requests are retried by [`Bitfinex`](crate::bitfinex::Bitfinex) and
+/// [`AsyncBitfinex`](crate::bitfinex::AsyncBitfinex).
+///
+/// The delay before the n-th retry is `initial_backoff * multiplier^(n - 1)`, capped to `max_backoff`, and
+/// drawn between half and the full value when `jitter` is set. Rate limit errors come with a 60 seconds ban of
+/// the IP, they are retried after at least `rate_limit_backoff`.
+#[derive(Debug, Clone, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    #[builder(default = "3")]
+    max_attempts: u32,
+    #[builder(default = "Duration::from_millis(500)")]
+    initial_backoff: Duration,
+    #[builder(default = "Duration::from_secs(30)")]
+    max_backoff: Duration,
+    /// Must be finite and at least 1, so that the backoff never decreases.
+    #[builder(default = "2.")]
+    multiplier: f64,
+    #[builder(default = "true")]
+    jitter: bool,
+    #[builder(default = "Duration::from_secs(60)")]
+    rate_limit_backoff: Duration,
+    /// Decides whether a failure is retried, given whether the request is idempotent.
+    #[builder(default = "default_retry_on")]
+    retry_on: fn(ErrorClass, bool) -> bool,
+}
+
+impl RetryPolicyBuilder {
+    fn validate(&self) -> Result<(), String> {
+        match self.multiplier {
+            Some(multiplier) if !(multiplier.is_finite() && multiplier >= 1.) => {
+                Err(format!("Invalid backoff multiplier {multiplier}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// A policy making a single attempt.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// The delay to wait for after the failed attempt number `attempt` (starting at 1), returns `None`
+    /// when the request must not be retried.
+    pub fn delay(&self, attempt: u32, class: ErrorClass, idempotent: bool) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(self.retry_on)(class, idempotent) {
+            return None;
+        }
+
+        let backoff = Duration::from_secs_f64(
+            (self.initial_backoff.as_secs_f64() * self.multiplier.powi(attempt as i32 - 1))
+                .min(self.max_backoff.as_secs_f64()),
+        );
+
+        let backoff = if self.jitter {
+            let random = RandomState::new().build_hasher().finish() as f64 / u64::MAX as f64;
+            backoff.mul_f64(0.5 + random / 2.)
+        } else {
+            backoff
+        };
+
+        if class == ErrorClass::RateLimit {
+            Some(backoff.max(self.rate_limit_backoff))
+        } else {
+            Some(backoff)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build().unwrap()
+    }
+}
@@ -0,0 +1,264 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::api::{
+    authenticated::{
+        funding::types::FundingOffer,
+        orders::types::{Order, OrderType},
+        positions::types::Position,
+        wallets::WalletType,
+    },
+    common::PlaceHolder,
+};
+
+use super::{channels::from_value, error::WsError};
+
+/// An event received on the authenticated channel (`chanId` 0).
+#[derive(Debug)]
+pub enum AccountEvent {
+    OrdersSnapshot(Vec<Order>),
+    OrderNew(Order),
+    OrderUpdate(Order),
+    OrderCancel(Order),
+    FundingOffersSnapshot(Vec<FundingOffer>),
+    FundingOfferNew(FundingOffer),
+    FundingOfferUpdate(FundingOffer),
+    FundingOfferCancel(FundingOffer),
+    WalletsSnapshot(Vec<AccountWallet>),
+    WalletUpdate(AccountWallet),
+    TradeExecuted(AccountTrade),
+    TradeUpdate(AccountTrade),
+    PositionsSnapshot(Vec<Position>),
+    PositionNew(Position),
+    PositionUpdate(Position),
+    PositionClose(Position),
+    Notification(Notification),
+}
+
+impl AccountEvent {
+    /// Decodes the payload of an account event, returns `None` for unsupported event codes.
+    pub(crate) fn decode(code: &str, payload: Value) -> Result<Option<Self>, WsError> {
+        let event = match code {
+            "os" => Self::OrdersSnapshot(from_value(payload)?),
+            "on" => Self::OrderNew(from_value(payload)?),
+            "ou" => Self::OrderUpdate(from_value(payload)?),
+            "oc" => Self::OrderCancel(from_value(payload)?),
+            "fos" => Self::FundingOffersSnapshot(from_value(payload)?),
+            "fon" => Self::FundingOfferNew(from_value(payload)?),
+            "fou" => Self::FundingOfferUpdate(from_value(payload)?),
+            "foc" => Self::FundingOfferCancel(from_value(payload)?),
+            "ws" => Self::WalletsSnapshot(from_value(payload)?),
+            "wu" => Self::WalletUpdate(from_value(payload)?),
+            "te" => Self::TradeExecuted(from_value(payload)?),
+            "tu" => Self::TradeUpdate(from_value(payload)?),
+            "ps" => Self::PositionsSnapshot(from_value(payload)?),
+            "pn" => Self::PositionNew(from_value(payload)?),
+            "pu" => Self::PositionUpdate(from_value(payload)?),
+            "pc" => Self::PositionClose(from_value(payload)?),
+            "n" => Self::Notification(from_value(payload)?),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+/// https://docs.bitfinex.com/reference/ws-auth-wallets
+///
+/// Unlike the REST wallets, `available_balance` is only sent when it has been computed, e.g. after
+/// a `calc` input message.
+#[derive(Debug)]
+pub struct AccountWallet {
+    pub ty: WalletType,
+    pub currency: String,
+    pub balance: f64,
+    pub unsettled_interest: f64,
+    pub available_balance: Option<f64>,
+    pub last_change: Option<String>,
+    pub trade_details: Option<Value>,
+}
+
+impl<'de> Deserialize<'de> for AccountWallet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct AccountWalletRaw(
+            WalletType,
+            String,
+            f64,
+            f64,
+            Option<f64>,
+            #[serde(default)] Option<String>,
+            #[serde(default)] Option<Value>,
+        );
+
+        impl From<AccountWalletRaw> for AccountWallet {
+            fn from(value: AccountWalletRaw) -> Self {
+                let AccountWalletRaw(
+                    ty,
+                    currency,
+                    balance,
+                    unsettled_interest,
+                    available_balance,
+                    last_change,
+                    trade_details,
+                ) = value;
+
+                Self {
+                    ty,
+                    currency,
+                    balance,
+                    unsettled_interest,
+                    available_balance,
+                    last_change,
+                    trade_details,
+                }
+            }
+        }
+
+        let raw = AccountWalletRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+/// https://docs.bitfinex.com/reference/ws-auth-trades
+///
+/// The fee fields are only filled in on `tu` messages.
+#[derive(Debug)]
+pub struct AccountTrade {
+    pub id: u64,
+    pub symbol: String,
+    pub mts_create: u64,
+    pub order_id: u64,
+    pub exec_amount: f64,
+    pub exec_price: f64,
+    pub order_type: OrderType,
+    pub order_price: f64,
+    pub maker: bool,
+    pub fee: Option<f64>,
+    pub fee_currency: Option<String>,
+    pub cid: Option<u64>,
+}
+
+impl<'de> Deserialize<'de> for AccountTrade {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Debug, Deserialize)]
+        struct AccountTradeRaw(
+            u64,
+            String,
+            u64,
+            u64,
+            f64,
+            f64,
+            OrderType,
+            f64,
+            i8,
+            Option<f64>,
+            Option<String>,
+            #[serde(default)] Option<u64>,
+        );
+
+        impl From<AccountTradeRaw> for AccountTrade {
+            fn from(value: AccountTradeRaw) -> Self {
+                let AccountTradeRaw(
+                    id,
+                    symbol,
+                    mts_create,
+                    order_id,
+                    exec_amount,
+                    exec_price,
+                    order_type,
+                    order_price,
+                    maker,
+                    fee,
+                    fee_currency,
+                    cid,
+                ) = value;
+
+                Self {
+                    id,
+                    symbol,
+                    mts_create,
+                    order_id,
+                    exec_amount,
+                    exec_price,
+                    order_type,
+                    order_price,
+                    maker: maker == 1,
+                    fee,
+                    fee_currency,
+                    cid,
+                }
+            }
+        }
+
+        let raw = AccountTradeRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
+
+/// https://docs.bitfinex.com/reference/ws-auth-notifications
+///
+/// The shape of `notify_info` depends on the notification type (e.g. an order for `on-req`).
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub mts: u64,
+    pub ty: String,
+    pub message_id: Option<u64>,
+    pub notify_info: Value,
+    pub code: Option<u64>,
+    pub status: String,
+    pub text: String,
+}
+
+impl Notification {
+    /// Decodes `notify_info` as an order, as sent back for `on-req`, `ou-req` and `oc-req` notifications.
+    pub fn order(&self) -> Option<Order> {
+        serde_json::from_value(self.notify_info.clone()).ok()
+    }
+}
+
+impl<'de> Deserialize<'de> for Notification {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct NotificationRaw(
+            u64,
+            String,
+            Option<u64>,
+            PlaceHolder,
+            Value,
+            Option<u64>,
+            String,
+            String,
+        );
+
+        impl From<NotificationRaw> for Notification {
+            fn from(value: NotificationRaw) -> Self {
+                let NotificationRaw(mts, ty, message_id, _, notify_info, code, status, text) =
+                    value;
+
+                Self {
+                    mts,
+                    ty,
+                    message_id,
+                    notify_info,
+                    code,
+                    status,
+                    text,
+                }
+            }
+        }
+
+        let raw = NotificationRaw::deserialize(deserializer)?;
+        Ok(raw.into())
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    any,
+    collections::{HashMap, HashSet},
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use super::{
+    account::AccountEvent,
+    error::WsError,
+    events::{TradeEventKind, WsEvent},
+    subscription::{SubscribedRaw, Subscription},
+};
+
+#[derive(Debug, Deserialize)]
+struct PlatformRaw {
+    status: u8,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum EventRaw {
+    Info {
+        version: Option<u64>,
+        platform: Option<PlatformRaw>,
+        code: Option<u64>,
+        msg: Option<String>,
+    },
+    Subscribed {
+        #[serde(rename = "chanId")]
+        chan_id: u64,
+        #[serde(flatten)]
+        subscription: SubscribedRaw,
+    },
+    Unsubscribed {
+        #[serde(rename = "chanId")]
+        chan_id: u64,
+    },
+    Error {
+        code: u64,
+        msg: String,
+    },
+    Conf {
+        status: String,
+        flags: Option<u64>,
+    },
+    Auth {
+        status: String,
+        #[serde(rename = "userId")]
+        user_id: Option<u64>,
+        code: Option<u64>,
+        msg: Option<String>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/// Keeps track of the subscribed channels and decodes the incoming messages.
+#[derive(Debug, Default)]
+pub(crate) struct Channels {
+    subscriptions: HashMap<u64, Subscription>,
+    /// The book channels whose snapshot has been received, the entries received after it are
+    /// bulk updates.
+    book_snapshots: HashSet<u64>,
+}
+
+impl Channels {
+    pub(crate) fn get(&self, chan_id: u64) -> Option<&Subscription> {
+        self.subscriptions.get(&chan_id)
+    }
+
+    /// Decodes a text frame, returns `None` for messages that carry nothing worth surfacing.
+    pub(crate) fn decode(&mut self, text: &str) -> Result<Option<WsEvent>, WsError> {
+        let v = serde_json::from_str::<Value>(text).map_err(|source| WsError::InvalidJson {
+            data: text.to_string(),
+            source,
+        })?;
+
+        match v {
+            Value::Object(_) => self.decode_event(v),
+            Value::Array(items) => self.decode_channel_message(items),
+            obj => Err(WsError::UnexpectedMessage { obj }),
+        }
+    }
+
+    fn decode_event(&mut self, v: Value) -> Result<Option<WsEvent>, WsError> {
+        let event = match from_value::<EventRaw>(v.clone())? {
+            EventRaw::Info {
+                version,
+                platform,
+                code,
+                msg,
+            } => WsEvent::Info {
+                version,
+                platform_status: platform.map(|platform| platform.status),
+                code,
+                msg,
+            },
+            EventRaw::Subscribed {
+                chan_id,
+                subscription,
+            } => {
+                let subscription = subscription
+                    .into_subscription()
+                    .ok_or(WsError::UnexpectedMessage { obj: v })?;
+                self.subscriptions.insert(chan_id, subscription.clone());
+                self.book_snapshots.remove(&chan_id);
+
+                WsEvent::Subscribed {
+                    chan_id,
+                    subscription,
+                }
+            }
+            EventRaw::Unsubscribed { chan_id } => {
+                self.subscriptions.remove(&chan_id);
+                self.book_snapshots.remove(&chan_id);
+                WsEvent::Unsubscribed { chan_id }
+            }
+            EventRaw::Error { code, msg } => WsEvent::Error { code, msg },
+            EventRaw::Conf { status, flags } => WsEvent::Conf { status, flags },
+            EventRaw::Auth {
+                status,
+                user_id: Some(user_id),
+                ..
+            } if status == "OK" => WsEvent::Authenticated { user_id },
+            EventRaw::Auth { code, msg, .. } => WsEvent::AuthFailed {
+                code,
+                msg: msg.unwrap_or_default(),
+            },
+            EventRaw::Other => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+
+    fn decode_channel_message(&mut self, items: Vec<Value>) -> Result<Option<WsEvent>, WsError> {
+        let chan_id = match (items.first().and_then(Value::as_u64), items.len()) {
+            (Some(chan_id), 2..) => chan_id,
+            _ => {
+                return Err(WsError::UnexpectedMessage {
+                    obj: Value::Array(items),
+                })
+            }
+        };
+
+        // Any trailing sequence number or timestamp is ignored.
+        let mut items = items.into_iter().skip(1);
+        let payload = items.next().unwrap_or_default();
+
+        if let (0, Value::String(code)) = (chan_id, &payload) {
+            if code == "hb" {
+                return Ok(Some(WsEvent::Heartbeat { chan_id }));
+            }
+
+            let payload = items.next().unwrap_or_default();
+            return Ok(AccountEvent::decode(code, payload)?.map(WsEvent::Account));
+        }
+
+        if let Value::String(code) = payload {
+            let kind = match code.as_str() {
+                "hb" => return Ok(Some(WsEvent::Heartbeat { chan_id })),
+                "cs" => {
+                    let checksum = items.next().unwrap_or_default();
+                    return Ok(Some(WsEvent::Checksum {
+                        chan_id,
+                        checksum: from_value(checksum)?,
+                    }));
+                }
+                "te" | "fte" => TradeEventKind::Executed,
+                "tu" | "ftu" => TradeEventKind::Updated,
+                _ => return Ok(None),
+            };
+
+            let trade = items.next().ok_or(WsError::UnexpectedMessage {
+                obj: Value::String(code),
+            })?;
+
+            return Ok(Some(WsEvent::Trade {
+                chan_id,
+                kind,
+                trade: from_value(trade)?,
+            }));
+        }
+
+        // Messages for channels we are not (or no longer) subscribed to are dropped.
+        let Some(subscription) = self.subscriptions.get(&chan_id) else {
+            return Ok(None);
+        };
+
+        let is_snapshot = match &payload {
+            Value::Array(entries) => entries.first().is_none_or(Value::is_array),
+            _ => return Err(WsError::UnexpectedMessage { obj: payload }),
+        };
+
+        // With `ConfFlag::BulkUpdates` the book updates are also sent as arrays of entries, only the
+        // first one received after subscribing is the snapshot.
+        let is_bulk_update = is_snapshot
+            && matches!(
+                subscription,
+                Subscription::Book { .. } | Subscription::RawBook { .. }
+            )
+            && !self.book_snapshots.insert(chan_id);
+
+        let event = match (subscription, is_snapshot) {
+            (Subscription::Ticker { .. }, _) => WsEvent::Ticker {
+                chan_id,
+                ticker: from_value(payload)?,
+            },
+            (Subscription::Trades { .. }, true) => WsEvent::TradesSnapshot {
+                chan_id,
+                trades: from_value(payload)?,
+            },
+            (Subscription::Trades { .. }, false) => {
+                return Err(WsError::UnexpectedMessage { obj: payload })
+            }
+            (Subscription::Book { .. }, true) if is_bulk_update => WsEvent::BookUpdates {
+                chan_id,
+                entries: from_value(payload)?,
+            },
+            (Subscription::Book { .. }, true) => WsEvent::BookSnapshot {
+                chan_id,
+                book: from_value(payload)?,
+            },
+            (Subscription::Book { .. }, false) => WsEvent::BookUpdate {
+                chan_id,
+                entry: from_value(payload)?,
+            },
+            (Subscription::RawBook { .. }, true) if is_bulk_update => WsEvent::RawBookUpdates {
+                chan_id,
+                entries: from_value(payload)?,
+            },
+            (Subscription::RawBook { .. }, true) => WsEvent::RawBookSnapshot {
+                chan_id,
+                book: from_value(payload)?,
+            },
+            (Subscription::RawBook { .. }, false) => WsEvent::RawBookUpdate {
+                chan_id,
+                entry: from_value(payload)?,
+            },
+            (Subscription::Candles { .. }, true) => WsEvent::CandlesSnapshot {
+                chan_id,
+                candles: from_value(payload)?,
+            },
+            (Subscription::Candles { .. }, false) => WsEvent::Candle {
+                chan_id,
+                candle: from_value(payload)?,
+            },
+        };
+
+        Ok(Some(event))
+    }
+}
+
+pub(super) fn from_value<T>(v: Value) -> Result<T, WsError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_value::<T>(v.clone()).map_err(|e| WsError::DataType {
+        typename: any::type_name::<T>(),
+        obj: v,
+        source: e,
+    })
+}
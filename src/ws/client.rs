@@ -0,0 +1,271 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::{
+    api::authenticated::orders::{
+        cancel_order::CancelOrder, cancel_orders::CancelOrders, submit_order::SubmitOrder,
+        update_order::UpdateOrder,
+    },
+    auth::Auth,
+};
+
+use super::{
+    account::{AccountEvent, Notification},
+    channels::Channels,
+    error::WsError,
+    events::WsEvent,
+    orders::{Cids, OrderOp, PendingRequests},
+    subscription::{ConfFlag, Subscription},
+};
+
+pub const PUB_WS_URL: &str = "wss://api-pub.bitfinex.com/ws/2";
+pub const AUTH_WS_URL: &str = "wss://api.bitfinex.com/ws/2";
+
+/// An asynchronous client for the Bitfinex WebSocket API.
+///
+/// Incoming messages are decoded by a background task and surfaced through [`BitfinexWs::next_event`].
+#[derive(Debug)]
+pub struct BitfinexWs {
+    /// The messages waiting to be written to the socket.
+    outgoing: mpsc::UnboundedSender<Message>,
+
+    /// The decoded incoming messages.
+    events: mpsc::UnboundedReceiver<Result<WsEvent, WsError>>,
+
+    /// The channels subscribed to, shared with the reading task.
+    channels: Arc<Mutex<Channels>>,
+
+    /// The order requests waiting for their notification, shared with the reading task.
+    pending: Arc<Mutex<PendingRequests>>,
+
+    /// The generator of client order ids for orders submitted without one.
+    cids: Cids,
+
+    /// The task reading from the socket.
+    reader: JoinHandle<()>,
+}
+
+impl BitfinexWs {
+    /// Connects to the public Bitfinex WebSocket API.
+    pub async fn connect() -> Result<Self, WsError> {
+        Self::connect_to(PUB_WS_URL).await
+    }
+
+    /// Connects to the authenticated Bitfinex WebSocket API and sends the `auth` event.
+    ///
+    /// The outcome is returned through a [`WsEvent::Authenticated`] or [`WsEvent::AuthFailed`] event,
+    /// account events are then surfaced as [`WsEvent::Account`].
+    pub async fn connect_auth(api_key: &str, secret_key: &str) -> Result<Self, WsError> {
+        let ws = Self::connect_to(AUTH_WS_URL).await?;
+        ws.authenticate(&Auth::new(api_key.to_string(), secret_key.to_string()))?;
+        Ok(ws)
+    }
+
+    /// Connects to the WebSocket server at the given URL.
+    pub async fn connect_to(url: &str) -> Result<Self, WsError> {
+        let (stream, _) = connect_async(url).await?;
+        let (mut sink, mut stream) = stream.split();
+
+        let (outgoing, mut outgoing_rx) = mpsc::unbounded_channel::<Message>();
+        let (events_tx, events) = mpsc::unbounded_channel();
+        let channels = Arc::new(Mutex::new(Channels::default()));
+        let pending = Arc::new(Mutex::new(PendingRequests::default()));
+
+        // Forward the outgoing messages to the socket.
+        tokio::spawn(async move {
+            while let Some(msg) = outgoing_rx.recv().await {
+                if sink.send(msg).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Decode the incoming messages until the socket or the client is closed.
+        let reader_channels = Arc::clone(&channels);
+        let reader_pending = Arc::clone(&pending);
+        let reader = tokio::spawn(async move {
+            while let Some(msg) = stream.next().await {
+                let event = match msg {
+                    Ok(Message::Text(text)) => {
+                        reader_channels.lock().unwrap().decode(&text).transpose()
+                    }
+                    Ok(Message::Close(_)) => break,
+                    Ok(_) => None,
+                    Err(e) => Some(Err(e.into())),
+                };
+
+                if let Some(Ok(WsEvent::Account(AccountEvent::Notification(notification)))) = &event
+                {
+                    reader_pending.lock().unwrap().resolve(notification);
+                }
+
+                if let Some(event) = event {
+                    if events_tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+
+            // Fail the requests still waiting for a notification.
+            *reader_pending.lock().unwrap() = PendingRequests::default();
+        });
+
+        Ok(Self {
+            outgoing,
+            events,
+            channels,
+            pending,
+            cids: Cids::default(),
+            reader,
+        })
+    }
+
+    /// Authenticates the connection, giving access to the account events on channel 0.
+    pub fn authenticate(&self, auth: &Auth) -> Result<(), WsError> {
+        self.send(auth.ws_auth_message()?)
+    }
+
+    /// Sets the connection configuration flags, confirmed through a [`WsEvent::Conf`] event.
+    pub fn configure(&self, flags: &[ConfFlag]) -> Result<(), WsError> {
+        self.send(json!({
+            "event": "conf",
+            "flags": flags.iter().fold(0, |sum, flag| sum | *flag as u64),
+        }))
+    }
+
+    /// Subscribes to a channel, the assigned channel id is returned through a [`WsEvent::Subscribed`] event.
+    pub fn subscribe(&self, subscription: &Subscription) -> Result<(), WsError> {
+        self.send(subscription.subscribe_message())
+    }
+
+    /// Unsubscribes from a channel, confirmed through a [`WsEvent::Unsubscribed`] event.
+    pub fn unsubscribe(&self, chan_id: u64) -> Result<(), WsError> {
+        self.send(json!({
+            "event": "unsubscribe",
+            "chanId": chan_id,
+        }))
+    }
+
+    /// Unsubscribes from a channel and subscribes again to it, e.g. to resync an
+    /// [`OrderBook`](super::order_book::OrderBook) after a checksum mismatch.
+    ///
+    /// The new subscription is confirmed through a [`WsEvent::Subscribed`] event with a new `chan_id`.
+    pub fn resubscribe(&self, chan_id: u64) -> Result<(), WsError> {
+        let subscription = self
+            .subscription(chan_id)
+            .ok_or(WsError::UnknownChannel { chan_id })?;
+        self.unsubscribe(chan_id)?;
+        self.subscribe(&subscription)
+    }
+
+    /// Submits an order on the authenticated connection.
+    ///
+    /// The returned future resolves with the matching `on-req` notification, orders without a `cid`
+    /// are assigned one.
+    pub fn submit_order(
+        &self,
+        order: &SubmitOrder,
+    ) -> Result<impl Future<Output = Result<Notification, WsError>>, WsError> {
+        let op = OrderOp::Submit(order);
+        let payload = op.payload(&self.cids);
+        let cid = payload["cid"].as_u64();
+        self.request(op.code(), payload, cid)
+    }
+
+    /// Updates an order, the returned future resolves with the matching `ou-req` notification.
+    pub fn update_order(
+        &self,
+        order: &UpdateOrder,
+    ) -> Result<impl Future<Output = Result<Notification, WsError>>, WsError> {
+        let op = OrderOp::Update(order);
+        let payload = op.payload(&self.cids);
+        let id = payload["id"].as_u64();
+        self.request(op.code(), payload, id)
+    }
+
+    /// Cancels an order, the returned future resolves with the matching `oc-req` notification.
+    pub fn cancel_order(
+        &self,
+        order: &CancelOrder,
+    ) -> Result<impl Future<Output = Result<Notification, WsError>>, WsError> {
+        let op = OrderOp::Cancel(order);
+        let payload = op.payload(&self.cids);
+        let id = payload["id"].as_u64();
+        self.request(op.code(), payload, id)
+    }
+
+    /// Cancels multiple orders, the returned future resolves with the next `oc_multi-req` notification.
+    pub fn cancel_orders(
+        &self,
+        orders: &CancelOrders,
+    ) -> Result<impl Future<Output = Result<Notification, WsError>>, WsError> {
+        let op = OrderOp::CancelMulti(orders);
+        let payload = op.payload(&self.cids);
+        self.request(op.code(), payload, None)
+    }
+
+    /// Sends a batch of order operations, the returned future resolves with the next `ox_multi-req` notification.
+    pub fn order_multi(
+        &self,
+        ops: &[OrderOp],
+    ) -> Result<impl Future<Output = Result<Notification, WsError>>, WsError> {
+        let payload = ops
+            .iter()
+            .map(|op| json!([op.code(), op.payload(&self.cids)]))
+            .collect();
+        self.request("ox_multi", payload, None)
+    }
+
+    /// Returns the subscription associated with a channel id.
+    pub fn subscription(&self, chan_id: u64) -> Option<Subscription> {
+        self.channels.lock().unwrap().get(chan_id).cloned()
+    }
+
+    /// Waits for the next event, returns `None` once the connection is closed.
+    pub async fn next_event(&mut self) -> Option<Result<WsEvent, WsError>> {
+        self.events.recv().await
+    }
+
+    /// Closes the connection.
+    pub fn close(&self) -> Result<(), WsError> {
+        self.outgoing
+            .send(Message::Close(None))
+            .map_err(|_| WsError::Closed)
+    }
+
+    fn request(
+        &self,
+        op: &'static str,
+        payload: Value,
+        id: Option<u64>,
+    ) -> Result<impl Future<Output = Result<Notification, WsError>>, WsError> {
+        // Register the request before sending it so that the notification can't be missed.
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(op, id, tx);
+        self.send(json!([0, op, null, payload]))?;
+
+        Ok(async move { rx.await.map_err(|_| WsError::Closed) })
+    }
+
+    fn send(&self, msg: Value) -> Result<(), WsError> {
+        self.outgoing
+            .send(Message::Text(msg.to_string()))
+            .map_err(|_| WsError::Closed)
+    }
+}
+
+impl Drop for BitfinexWs {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
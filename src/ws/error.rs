@@ -0,0 +1,73 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WsError {
+    /// The WebSocket connection failed.
+    #[error("Communication with Bitfinex: {}", source)]
+    Communication {
+        source: Box<tokio_tungstenite::tungstenite::Error>,
+    },
+
+    /// The connection has been closed.
+    #[error("WebSocket connection closed")]
+    Closed,
+
+    /// A malformed JSON payload has been received.
+    #[error("Invalid JSON received: {}", source)]
+    InvalidJson {
+        /// The raw message.
+        data: String,
+
+        /// The JSON parse error.
+        source: serde_json::Error,
+    },
+
+    /// Failed to parse an expected data type from JSON.
+    #[error("Could not parse {} data from JSON: {}", typename, source)]
+    DataType {
+        /// The name of the type that could not be deserialized.
+        typename: &'static str,
+
+        /// The JSON payload to parse.
+        obj: serde_json::Value,
+
+        /// The JSON parse error.
+        source: serde_json::Error,
+    },
+
+    /// No subscription is known for the channel.
+    #[error("Unknown channel {}", chan_id)]
+    UnknownChannel { chan_id: u64 },
+
+    /// The local order book no longer matches the checksum sent by the server, it must be resubscribed.
+    #[error(
+        "Checksum mismatch on channel {}: expected {}, computed {}",
+        chan_id,
+        expected,
+        computed
+    )]
+    ChecksumMismatch {
+        chan_id: u64,
+        expected: i32,
+        computed: i32,
+    },
+
+    /// A message has been received with an unexpected shape.
+    #[error("Unexpected message: {}", obj)]
+    UnexpectedMessage { obj: serde_json::Value },
+
+    /// The nonce to authenticate with could not be generated.
+    #[error("Could not generate a nonce: {}", source)]
+    Nonce {
+        #[from]
+        source: std::io::Error,
+    },
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for WsError {
+    fn from(source: tokio_tungstenite::tungstenite::Error) -> Self {
+        WsError::Communication {
+            source: Box::new(source),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use serde::Deserialize;
+
+use crate::api::public::{
+    book::{
+        book::{BookFundingResp, BookResp, BookTradingResp},
+        raw_book::{RawBookFundingResp, RawBookResp, RawBookTradingResp},
+    },
+    candles::CandleResp,
+    ticker::TickerResp,
+    trades::{TradeFundingResp, TradeTradingResp, TradesResp},
+};
+
+use super::{account::AccountEvent, subscription::Subscription};
+
+/// An event received on the WebSocket connection.
+#[derive(Debug)]
+pub enum WsEvent {
+    /// Sent by the server on connection and on platform status changes.
+    Info {
+        version: Option<u64>,
+        platform_status: Option<u8>,
+        code: Option<u64>,
+        msg: Option<String>,
+    },
+    Subscribed {
+        chan_id: u64,
+        subscription: Subscription,
+    },
+    Unsubscribed {
+        chan_id: u64,
+    },
+    Conf {
+        status: String,
+        flags: Option<u64>,
+    },
+    Error {
+        code: u64,
+        msg: String,
+    },
+    Authenticated {
+        user_id: u64,
+    },
+    AuthFailed {
+        code: Option<u64>,
+        msg: String,
+    },
+    Heartbeat {
+        chan_id: u64,
+    },
+    Ticker {
+        chan_id: u64,
+        ticker: TickerResp,
+    },
+    TradesSnapshot {
+        chan_id: u64,
+        trades: TradesResp,
+    },
+    Trade {
+        chan_id: u64,
+        kind: TradeEventKind,
+        trade: Trade,
+    },
+    BookSnapshot {
+        chan_id: u64,
+        book: BookResp,
+    },
+    BookUpdate {
+        chan_id: u64,
+        entry: BookEntry,
+    },
+    /// Several book updates sent at once, when [`ConfFlag::BulkUpdates`](super::subscription::ConfFlag::BulkUpdates) is enabled.
+    BookUpdates {
+        chan_id: u64,
+        entries: Vec<BookEntry>,
+    },
+    RawBookSnapshot {
+        chan_id: u64,
+        book: RawBookResp,
+    },
+    RawBookUpdate {
+        chan_id: u64,
+        entry: RawBookEntry,
+    },
+    /// Several raw book updates sent at once, when [`ConfFlag::BulkUpdates`](super::subscription::ConfFlag::BulkUpdates) is enabled.
+    RawBookUpdates {
+        chan_id: u64,
+        entries: Vec<RawBookEntry>,
+    },
+    /// The CRC32 checksum of the top 25 levels of a book, see [`OrderBook`](super::order_book::OrderBook).
+    Checksum {
+        chan_id: u64,
+        checksum: i32,
+    },
+    CandlesSnapshot {
+        chan_id: u64,
+        candles: Vec<CandleResp>,
+    },
+    Candle {
+        chan_id: u64,
+        candle: CandleResp,
+    },
+    /// An event received on the authenticated channel.
+    Account(AccountEvent),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeEventKind {
+    /// `te`: the trade has just been executed.
+    Executed,
+    /// `tu`: the trade has been assigned its final id.
+    Updated,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Trade {
+    Trading(TradeTradingResp),
+    Funding(TradeFundingResp),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum BookEntry {
+    Trading(BookTradingResp),
+    Funding(BookFundingResp),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RawBookEntry {
+    Trading(RawBookTradingResp),
+    Funding(RawBookFundingResp),
+}
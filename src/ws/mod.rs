@@ -0,0 +1,9 @@
+mod channels;
+
+pub mod account;
+pub mod client;
+pub mod error;
+pub mod events;
+pub mod order_book;
+pub mod orders;
+pub mod subscription;
@@ -0,0 +1,287 @@
+use std::cmp::Ordering;
+
+use crate::api::public::book::{
+    book::{BookFundingResp, BookResp, BookTradingResp},
+    raw_book::{RawBookFundingResp, RawBookResp, RawBookTradingResp},
+};
+
+use super::{
+    error::WsError,
+    events::{BookEntry, RawBookEntry, WsEvent},
+};
+
+/// The number of levels per side covered by the `cs` checksum.
+const CHECKSUM_DEPTH: usize = 25;
+
+/// A price level of an aggregated book, or a single order of a raw book.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookLevel {
+    /// The order id, only set for raw books.
+    pub id: Option<u64>,
+    /// The price, or the rate for funding books.
+    pub price: f64,
+    /// The period in days, only set for funding books.
+    pub period: Option<u64>,
+    /// The number of orders at this level, always 1 for raw books.
+    pub count: u64,
+    /// The amount as sent by the server, its sign tells the side of the book.
+    pub amount: f64,
+}
+
+impl BookLevel {
+    fn same_key(&self, other: &BookLevel) -> bool {
+        match (self.id, other.id) {
+            (Some(id), Some(other_id)) => id == other_id,
+            _ => self.price == other.price && self.period == other.period,
+        }
+    }
+}
+
+/// A local copy of a `book` channel, kept up to date from the snapshot and update events.
+///
+/// Works with both aggregated ([`Subscription::Book`](super::subscription::Subscription::Book))
+/// and raw ([`Subscription::RawBook`](super::subscription::Subscription::RawBook)) books. When
+/// checksums are enabled with [`ConfFlag::Checksum`](super::subscription::ConfFlag::Checksum),
+/// each `cs` message is checked against the local state and a mismatch is reported as
+/// [`WsError::ChecksumMismatch`]: the book is then cleared and the channel must be resubscribed.
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    chan_id: u64,
+    bids: Vec<BookLevel>,
+    asks: Vec<BookLevel>,
+}
+
+impl OrderBook {
+    /// Creates an empty book for the channel `chan_id`.
+    pub fn new(chan_id: u64) -> Self {
+        Self {
+            chan_id,
+            bids: Vec::new(),
+            asks: Vec::new(),
+        }
+    }
+
+    pub fn chan_id(&self) -> u64 {
+        self.chan_id
+    }
+
+    /// Applies an event to the book, events for other channels are ignored.
+    pub fn handle_event(&mut self, event: &WsEvent) -> Result<(), WsError> {
+        match event {
+            WsEvent::BookSnapshot { chan_id, book } if *chan_id == self.chan_id => {
+                self.clear();
+                match book {
+                    BookResp::BookTradingResp(levels) => {
+                        levels.iter().for_each(|level| self.apply_trading(level))
+                    }
+                    BookResp::BookFundingResp(levels) => {
+                        levels.iter().for_each(|level| self.apply_funding(level))
+                    }
+                }
+            }
+            WsEvent::BookUpdate { chan_id, entry } if *chan_id == self.chan_id => {
+                self.apply_entry(entry)
+            }
+            WsEvent::BookUpdates { chan_id, entries } if *chan_id == self.chan_id => {
+                entries.iter().for_each(|entry| self.apply_entry(entry))
+            }
+            WsEvent::RawBookSnapshot { chan_id, book } if *chan_id == self.chan_id => {
+                self.clear();
+                match book {
+                    RawBookResp::RawBookTradingResp(orders) => orders
+                        .iter()
+                        .for_each(|order| self.apply_raw_trading(order)),
+                    RawBookResp::RawBookFundingResp(offers) => offers
+                        .iter()
+                        .for_each(|offer| self.apply_raw_funding(offer)),
+                }
+            }
+            WsEvent::RawBookUpdate { chan_id, entry } if *chan_id == self.chan_id => {
+                self.apply_raw_entry(entry)
+            }
+            WsEvent::RawBookUpdates { chan_id, entries } if *chan_id == self.chan_id => {
+                entries.iter().for_each(|entry| self.apply_raw_entry(entry))
+            }
+            WsEvent::Checksum { chan_id, checksum } if *chan_id == self.chan_id => {
+                let computed = self.checksum();
+                if computed != *checksum {
+                    self.clear();
+                    return Err(WsError::ChecksumMismatch {
+                        chan_id: self.chan_id,
+                        expected: *checksum,
+                        computed,
+                    });
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Removes every level, e.g. before resubscribing.
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    /// The bids, best first.
+    pub fn bids(&self) -> &[BookLevel] {
+        &self.bids
+    }
+
+    /// The asks, best first.
+    pub fn asks(&self) -> &[BookLevel] {
+        &self.asks
+    }
+
+    pub fn best_bid(&self) -> Option<&BookLevel> {
+        self.bids.first()
+    }
+
+    pub fn best_ask(&self) -> Option<&BookLevel> {
+        self.asks.first()
+    }
+
+    /// The difference between the best ask and the best bid.
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// The number of bids and asks.
+    pub fn depth(&self) -> (usize, usize) {
+        (self.bids.len(), self.asks.len())
+    }
+
+    /// The total (absolute) amount of the bids at or above `price`.
+    pub fn bid_volume(&self, price: f64) -> f64 {
+        volume(&self.bids, |level| level.price >= price)
+    }
+
+    /// The total (absolute) amount of the asks at or below `price`.
+    pub fn ask_volume(&self, price: f64) -> f64 {
+        volume(&self.asks, |level| level.price <= price)
+    }
+
+    /// The checksum of the top 25 levels of each side, computed the way the server does.
+    ///
+    /// https://docs.bitfinex.com/docs/ws-websocket-checksum
+    pub fn checksum(&self) -> i32 {
+        let mut values = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+        for i in 0..CHECKSUM_DEPTH {
+            for level in [self.bids.get(i), self.asks.get(i)].into_iter().flatten() {
+                match level.id {
+                    Some(id) => values.push(id.to_string()),
+                    None => values.push(format_number(level.price)),
+                }
+                values.push(format_number(level.amount));
+            }
+        }
+
+        crc32fast::hash(values.join(":").as_bytes()) as i32
+    }
+
+    fn apply_entry(&mut self, entry: &BookEntry) {
+        match entry {
+            BookEntry::Trading(level) => self.apply_trading(level),
+            BookEntry::Funding(level) => self.apply_funding(level),
+        }
+    }
+
+    fn apply_raw_entry(&mut self, entry: &RawBookEntry) {
+        match entry {
+            RawBookEntry::Trading(order) => self.apply_raw_trading(order),
+            RawBookEntry::Funding(offer) => self.apply_raw_funding(offer),
+        }
+    }
+
+    fn apply_trading(&mut self, level: &BookTradingResp) {
+        let level = BookLevel {
+            id: None,
+            price: level.price,
+            period: None,
+            count: level.count,
+            amount: level.amount,
+        };
+        let is_bid = level.amount > 0.;
+        self.apply(level, is_bid)
+    }
+
+    fn apply_funding(&mut self, level: &BookFundingResp) {
+        let level = BookLevel {
+            id: None,
+            price: level.rate,
+            period: Some(level.period),
+            count: level.count,
+            amount: level.amount,
+        };
+        let is_bid = level.amount < 0.;
+        self.apply(level, is_bid)
+    }
+
+    fn apply_raw_trading(&mut self, order: &RawBookTradingResp) {
+        let level = BookLevel {
+            id: Some(order.order_id),
+            price: order.price,
+            period: None,
+            count: if order.price == 0. { 0 } else { 1 },
+            amount: order.amount,
+        };
+        let is_bid = level.amount > 0.;
+        self.apply(level, is_bid)
+    }
+
+    fn apply_raw_funding(&mut self, offer: &RawBookFundingResp) {
+        let level = BookLevel {
+            id: Some(offer.offer_id),
+            price: offer.rate,
+            period: Some(offer.period),
+            count: if offer.rate == 0. { 0 } else { 1 },
+            amount: offer.amount,
+        };
+        let is_bid = level.amount < 0.;
+        self.apply(level, is_bid)
+    }
+
+    /// Inserts, updates or (when `count` is 0) removes a level.
+    fn apply(&mut self, level: BookLevel, is_bid: bool) {
+        self.bids.retain(|other| !other.same_key(&level));
+        self.asks.retain(|other| !other.same_key(&level));
+
+        if level.count == 0 {
+            return;
+        }
+
+        let (side, compare): (_, fn(&BookLevel, &BookLevel) -> Ordering) = if is_bid {
+            (&mut self.bids, |a, b| b.price.total_cmp(&a.price))
+        } else {
+            (&mut self.asks, |a, b| a.price.total_cmp(&b.price))
+        };
+
+        let index = side.partition_point(|other| {
+            compare(other, &level)
+                .then(other.period.cmp(&level.period))
+                .then(other.id.cmp(&level.id))
+                .is_lt()
+        });
+        side.insert(index, level);
+    }
+}
+
+fn volume(levels: &[BookLevel], include: impl Fn(&BookLevel) -> bool) -> f64 {
+    levels
+        .iter()
+        .take_while(|level| include(level))
+        .map(|level| level.amount.abs())
+        .sum()
+}
+
+/// Formats a number the way JavaScript's `Number.prototype.toString` does, which is what
+/// the server uses to build the checksum string.
+fn format_number(n: f64) -> String {
+    if n != 0. && n.abs() < 1e-6 {
+        format!("{n:e}")
+    } else {
+        n.to_string()
+    }
+}
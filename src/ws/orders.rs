@@ -0,0 +1,92 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+pub use crate::api::authenticated::orders::order_multi_op::OrderOp;
+
+use super::account::Notification;
+
+impl<'a> OrderOp<'a> {
+    /// The operation payload, new orders are assigned a `cid` from `cids` when they don't have one.
+    pub(crate) fn payload(&self, cids: &Cids) -> Value {
+        let mut payload = self.json_value();
+        if let (OrderOp::Submit(_), None) = (self, payload.get("cid")) {
+            payload["cid"] = Value::from(cids.next());
+        }
+
+        payload
+    }
+}
+
+/// Generates strictly increasing client order ids, seeded with the current timestamp.
+#[derive(Debug)]
+pub(crate) struct Cids(AtomicU64);
+
+impl Default for Cids {
+    fn default() -> Self {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        Self(AtomicU64::new(since_epoch.as_millis() as u64))
+    }
+}
+
+impl Cids {
+    fn next(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// The requests waiting for their `n` notification.
+///
+/// A request is identified by the notification type it expects (e.g. `on-req`) and, when
+/// available, the `cid` (new orders) or `id` (updates and cancellations) of the order.
+#[derive(Debug, Default)]
+pub(crate) struct PendingRequests {
+    requests: Vec<(String, Option<u64>, oneshot::Sender<Notification>)>,
+}
+
+impl PendingRequests {
+    pub(crate) fn insert(&mut self, op: &str, id: Option<u64>, tx: oneshot::Sender<Notification>) {
+        // Drop the requests whose future has been dropped.
+        self.requests.retain(|(_, _, tx)| !tx.is_closed());
+        self.requests.push((format!("{op}-req"), id, tx));
+    }
+
+    pub(crate) fn resolve(&mut self, notification: &Notification) {
+        let order = notification
+            .notify_info
+            .as_array()
+            .filter(|info| !info.is_empty());
+        let id = order
+            .and_then(|order| match notification.ty.as_str() {
+                "on-req" => order.get(2),
+                "ou-req" | "oc-req" => order.first(),
+                _ => None,
+            })
+            .and_then(Value::as_u64);
+
+        let find = |matches: &dyn Fn(Option<u64>) -> bool| {
+            self.requests
+                .iter()
+                .position(|(ty, request_id, _)| *ty == notification.ty && matches(*request_id))
+        };
+
+        // A request with an id only gets the notification for that id, the requests without an id
+        // are resolved in the order they were sent. A notification echoing no order (e.g. some
+        // rejections) can't be told apart and resolves the oldest request of its type.
+        let matching = match (order, id) {
+            (_, Some(id)) => find(&|request_id| request_id == Some(id))
+                .or_else(|| find(&|request_id| request_id.is_none())),
+            (Some(_), None) => find(&|request_id| request_id.is_none()),
+            (None, None) => find(&|_| true),
+        };
+
+        if let Some(index) = matching {
+            let (_, _, tx) = self.requests.remove(index);
+            let _ = tx.send(notification.clone());
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::Display;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::api::{
+    common::Symbol,
+    public::{
+        book::{book::Precision, common::Len},
+        candles::AvailableCandles,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    /// Realtime updates.
+    F0,
+    /// Updates batched every 2 seconds.
+    F1,
+}
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Frequency::F0 => write!(f, "F0"),
+            Frequency::F1 => write!(f, "F1"),
+        }
+    }
+}
+
+/// The connection configuration flags, sent through [`BitfinexWs::configure`](super::client::BitfinexWs::configure).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u64)]
+pub enum ConfFlag {
+    /// Adds a timestamp to each message.
+    Timestamp = 32768,
+    /// Adds a sequence number to each message.
+    SeqAll = 65536,
+    /// Enables the `cs` checksum messages on book channels.
+    Checksum = 131072,
+    /// Sends book updates in bulk, as [`WsEvent::BookUpdates`](super::events::WsEvent::BookUpdates)
+    /// and [`WsEvent::RawBookUpdates`](super::events::WsEvent::RawBookUpdates).
+    BulkUpdates = 536870912,
+}
+
+/// A public channel to subscribe to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Subscription {
+    Ticker {
+        symbol: String,
+    },
+    Trades {
+        symbol: String,
+    },
+    Book {
+        symbol: String,
+        precision: Precision,
+        frequency: Frequency,
+        len: Option<Len>,
+    },
+    RawBook {
+        symbol: String,
+        len: Option<Len>,
+    },
+    Candles {
+        key: String,
+    },
+}
+
+impl Subscription {
+    pub fn ticker<'a>(symbol: impl Into<Symbol<'a>>) -> Self {
+        Subscription::Ticker {
+            symbol: symbol.into().to_string(),
+        }
+    }
+
+    pub fn trades<'a>(symbol: impl Into<Symbol<'a>>) -> Self {
+        Subscription::Trades {
+            symbol: symbol.into().to_string(),
+        }
+    }
+
+    pub fn book<'a>(
+        symbol: impl Into<Symbol<'a>>,
+        precision: Precision,
+        frequency: Frequency,
+        len: Option<Len>,
+    ) -> Self {
+        Subscription::Book {
+            symbol: symbol.into().to_string(),
+            precision,
+            frequency,
+            len,
+        }
+    }
+
+    pub fn raw_book<'a>(symbol: impl Into<Symbol<'a>>, len: Option<Len>) -> Self {
+        Subscription::RawBook {
+            symbol: symbol.into().to_string(),
+            len,
+        }
+    }
+
+    pub fn candles(candles: AvailableCandles) -> Self {
+        Subscription::Candles {
+            key: candles.to_string(),
+        }
+    }
+
+    /// The `subscribe` event to send for this channel.
+    pub(crate) fn subscribe_message(&self) -> Value {
+        match self {
+            Subscription::Ticker { symbol } => json!({
+                "event": "subscribe",
+                "channel": "ticker",
+                "symbol": symbol,
+            }),
+            Subscription::Trades { symbol } => json!({
+                "event": "subscribe",
+                "channel": "trades",
+                "symbol": symbol,
+            }),
+            Subscription::Book {
+                symbol,
+                precision,
+                frequency,
+                len,
+            } => {
+                let mut msg = json!({
+                    "event": "subscribe",
+                    "channel": "book",
+                    "symbol": symbol,
+                    "prec": precision.to_string(),
+                    "freq": frequency.to_string(),
+                });
+                if let Some(len) = len {
+                    msg["len"] = Value::String((*len as u8).to_string());
+                }
+                msg
+            }
+            Subscription::RawBook { symbol, len } => {
+                let mut msg = json!({
+                    "event": "subscribe",
+                    "channel": "book",
+                    "symbol": symbol,
+                    "prec": "R0",
+                });
+                if let Some(len) = len {
+                    msg["len"] = Value::String((*len as u8).to_string());
+                }
+                msg
+            }
+            Subscription::Candles { key } => json!({
+                "event": "subscribe",
+                "channel": "candles",
+                "key": key,
+            }),
+        }
+    }
+}
+
+/// The fields of a `subscribed` event used to rebuild the [`Subscription`] it answers.
+#[derive(Debug, Deserialize)]
+pub(crate) struct SubscribedRaw {
+    channel: String,
+    symbol: Option<String>,
+    prec: Option<String>,
+    freq: Option<String>,
+    len: Option<String>,
+    key: Option<String>,
+}
+
+impl SubscribedRaw {
+    pub(crate) fn into_subscription(self) -> Option<Subscription> {
+        let len = match self.len.as_deref() {
+            Some("1") => Some(Len::One),
+            Some("25") => Some(Len::TwentyFive),
+            Some("100") => Some(Len::Hundred),
+            _ => None,
+        };
+
+        match self.channel.as_str() {
+            "ticker" => Some(Subscription::Ticker {
+                symbol: self.symbol?,
+            }),
+            "trades" => Some(Subscription::Trades {
+                symbol: self.symbol?,
+            }),
+            "book" if self.prec.as_deref() == Some("R0") => Some(Subscription::RawBook {
+                symbol: self.symbol?,
+                len,
+            }),
+            "book" => Some(Subscription::Book {
+                symbol: self.symbol?,
+                precision: match self.prec.as_deref() {
+                    Some("P0") | None => Precision::P0,
+                    Some("P1") => Precision::P1,
+                    Some("P2") => Precision::P2,
+                    Some("P3") => Precision::P3,
+                    Some("P4") => Precision::P4,
+                    Some(_) => return None,
+                },
+                frequency: match self.freq.as_deref() {
+                    Some("F1") => Frequency::F1,
+                    _ => Frequency::F0,
+                },
+                len,
+            }),
+            "candles" => Some(Subscription::Candles { key: self.key? }),
+            _ => None,
+        }
+    }
+}
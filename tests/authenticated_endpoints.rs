@@ -0,0 +1,1565 @@
+use bitfinex_api::{
+    api::{
+        authenticated::{
+            account::{
+                alerts::{AlertType, Alerts, AlertsResp},
+                balance_available::{BalanceAvailable, BalanceAvailableResp, BalanceType},
+                delete_alert::{DeleteAlert, DeleteAlertResp},
+                delete_settings::{DeleteSettings, DeleteSettingsResp},
+                deriv_collateral::{DerivativeCollateral, DerivativeCollateralResp},
+                deriv_collateral_limits::{
+                    DerivativeCollateralLimits, DerivativeCollateralLimitsResp,
+                },
+                key_permissions::{KeyPermissions, KeyPermissionsResp},
+                ledgers::{LedgerCategory, LedgerWalletType, Ledgers, LedgersResp},
+                login_history::{LoginHistory, LoginHistoryResp},
+                margin_info::{
+                    AllSymbolsMarginInfo, AllSymbolsMarginInfoResp, BaseMarginInfo,
+                    BaseMarginInfoResp, SymbolMarginInfo, SymbolMarginInfoResp,
+                },
+                set_alert::{SetAlert, SetAlertResp},
+                set_settings::{SetSettings, SetSettingsResp},
+                settings::{Settings, SettingsResp},
+                summary::{Summary, SummaryResp},
+                user_info::{UserInfo, UserInfoResp},
+            },
+            funding::{
+                active_funding_offers::{ActiveFundingOffers, ActiveFundingOffersResp},
+                cancel_all_funding_offers::{CancelAllFundingOffers, CancelAllFundingOffersResp},
+                cancel_funding_offer::{CancelFundingOffer, CancelFundingOfferResp},
+                close_funding::{CloseFunding, CloseFundingResp},
+                funding_auto_renew::{FundingAutoRenew, FundingAutoRenewResp},
+                funding_credits::{FundingCredits, FundingCreditsResp},
+                funding_credits_history::{FundingCreditsHistory, FundingCreditsHistoryResp},
+                funding_info::{FundingInfo, FundingInfoResp},
+                funding_loans::{FundingLoans, FundingLoansResp},
+                funding_loans_history::{FundingLoansHistory, FundingLoansHistoryResp},
+                funding_offers_history::{FundingOffersHistory, FundingOffersHistoryResp},
+                funding_trades::{FundingTrades, FundingTradesResp},
+                keep_funding::{KeepFunding, KeepFundingResp},
+                submit_funding_offer::{SubmitFundingOffer, SubmitFundingOfferResp},
+                types::{FundingKind, FundingOfferType, LoanOrCredit, LoanSide, RateType},
+            },
+            movements::{
+                deposit_address::{GetDepositAddress, GetDepositAddressResp},
+                deposit_addresses::{DepositAddresses, DepositAddressesResp},
+                deposit_invoice::{DepositInvoice, DepositInvoiceResp},
+                movement_info::{MovementInfo, MovementInfoResp},
+                movements_history::{MovementsHistory, MovementsHistoryResp},
+                types::MovementMethod,
+                withdraw::{Withdraw, WithdrawResp},
+            },
+            orders::{
+                cancel_order::{CancelOrder, CancelOrderResp},
+                cancel_orders::{CancelOrders, CancelOrdersResp, CancelOrdersType},
+                order_multi_op::{
+                    OrderMultiOp, OrderMultiOpBuilderError, OrderMultiOpResp, OrderOp,
+                },
+                order_trades::{OrderTrades, OrderTradesResp},
+                orders_history::{OrdersHistory, OrdersHistoryResp},
+                retrieve_orders::{RetrieveOrders, RetrieveOrdersResp},
+                retrieve_orders_by_symbol::{RetrieveOrdersBySymbol, RetrieveOrdersBySymbolResp},
+                submit_order::{SubmitOrder, SubmitOrderResp},
+                types::{OrderFlag, OrderType},
+                update_order::{UpdateOrder, UpdateOrderResp},
+            },
+            positions::{
+                active_positions::{ActivePositions, ActivePositionsResp},
+                claim_position::{ClaimPosition, ClaimPositionResp},
+                increase_position::{IncreasePosition, IncreasePositionResp},
+                increase_position_info::{IncreasePositionInfo, IncreasePositionInfoResp},
+                positions_audit::{PositionsAudit, PositionsAuditResp},
+                positions_history::{PositionsHistory, PositionsHistoryResp},
+                positions_snapshot::{PositionsSnapshot, PositionsSnapshotResp},
+                types::{MarginFundingType, PositionType},
+            },
+            pulse::{
+                add_pulse::{AddPulse, AddPulseResp},
+                delete_pulse::{DeletePulse, DeletePulseResp},
+                pulse_history::{PulseHistory, PulseHistoryResp},
+            },
+            trades::{Trades, TradesResp},
+            transfer::{Transfer, TransferBuilderError, TransferResp},
+            wallets::{WalletType, Wallets, WalletsResp},
+        },
+        query::{AsyncQuery, Query},
+    },
+    mock::{MockClient, MockResponse},
+};
+use http::Method;
+use serde_json::json;
+
+fn client(endpoint: &str, fixture: &'static str) -> MockClient {
+    MockClient::new().with(MockResponse::new(Method::POST, endpoint).data(fixture))
+}
+
+#[test]
+fn alerts() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/alerts")
+            .body(json!({ "type": "price" }))
+            .data(include_str!("fixtures/authenticated/alerts.json")),
+    );
+
+    let alerts: AlertsResp = Alerts::builder().build().unwrap().query(&client).unwrap();
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].key, "price:tBTCUSD:560.92");
+    assert_eq!(alerts[0].ty, AlertType::Price);
+    assert_eq!(alerts[1].symbol, "tETHUSD");
+    assert_eq!(alerts[1].price, 1800.);
+}
+
+#[test]
+fn balance_available() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/calc/order/avail")
+            .body(json!({ "symbol": "tBTCUSD", "type": "EXCHANGE", "dir": null }))
+            .data(include_str!(
+                "fixtures/authenticated/balance_available.json"
+            )),
+    );
+
+    let balance: BalanceAvailableResp = BalanceAvailable::builder()
+        .symbol("tBTCUSD")
+        .ty(BalanceType::Exchange)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(balance.amount_avail, 0.5);
+    assert!(client.requests()[0].is_authenticated);
+}
+
+#[test]
+fn delete_alert() {
+    let client = client(
+        "v2/auth/w/alert/price:tBTCUSD:560.92/del",
+        include_str!("fixtures/authenticated/delete_alert.json"),
+    );
+
+    let rsp: DeleteAlertResp = DeleteAlert::builder()
+        .key("price:tBTCUSD:560.92")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(rsp.success);
+}
+
+#[test]
+fn delete_settings() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/settings/del")
+            .body(json!({ "keys": ["api:theme"] }))
+            .data(include_str!("fixtures/authenticated/delete_settings.json")),
+    );
+
+    let rsp: DeleteSettingsResp = DeleteSettings::builder()
+        .keys(vec!["api:theme"])
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(rsp.success);
+}
+
+#[test]
+fn deriv_collateral() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/deriv/collateral/set")
+            .body(json!({ "symbol": "tBTCF0:USTF0", "collateral": "50" }))
+            .data(include_str!("fixtures/authenticated/deriv_collateral.json")),
+    );
+
+    let rsp: DerivativeCollateralResp = DerivativeCollateral::builder()
+        .symbol("tBTCF0:USTF0")
+        .collateral(50.)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(rsp.success);
+}
+
+#[test]
+fn deriv_collateral_limits() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/calc/deriv/collateral/limits")
+            .body(json!({ "symbol": "tBTCF0:USTF0" }))
+            .data(include_str!(
+                "fixtures/authenticated/deriv_collateral_limits.json"
+            )),
+    );
+
+    let rsp: DerivativeCollateralLimitsResp = DerivativeCollateralLimits::builder()
+        .symbol("tBTCF0:USTF0")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.min_collateral, 12.5);
+    assert_eq!(rsp.max_collateral, 980.25);
+}
+
+#[test]
+fn key_permissions() {
+    let client = client(
+        "v2/auth/r/permissions",
+        include_str!("fixtures/authenticated/key_permissions.json"),
+    );
+
+    let permissions: KeyPermissionsResp = KeyPermissions::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(permissions.len(), 8);
+    assert_eq!(permissions[1].scope, "history");
+    assert!(permissions[1].read);
+    assert!(!permissions[1].write);
+    assert!(permissions
+        .iter()
+        .any(|permission| permission.scope == "withdraw" && !permission.read));
+}
+
+#[test]
+fn ledgers() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/ledgers/USD/hist")
+            .body(json!({ "category": 28, "limit": 3 }))
+            .data(include_str!("fixtures/authenticated/ledgers.json")),
+    );
+
+    let ledgers: LedgersResp = Ledgers::builder()
+        .currency("USD")
+        .category(LedgerCategory::MarginFundingPayment)
+        .limit(3)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(ledgers.len(), 3);
+    assert_eq!(ledgers[0].id, 2531822314);
+    assert_eq!(
+        ledgers[0].wallet,
+        Some(LedgerWalletType::Known(WalletType::Exchange))
+    );
+    assert_eq!(ledgers[0].amount, 0.01644445);
+    assert_eq!(ledgers[1].wallet, None);
+    assert_eq!(ledgers[1].balance, 0.01444445);
+    assert!(ledgers[1].description.starts_with("Trading fees"));
+    // Unknown wallet types don't prevent the page from being decoded.
+    assert_eq!(
+        ledgers[2].wallet,
+        Some(LedgerWalletType::Unknown("contribution".to_string()))
+    );
+}
+
+#[test]
+fn login_history() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/logins/hist")
+            .body(json!({ "limit": 2 }))
+            .data(include_str!("fixtures/authenticated/login_history.json")),
+    );
+
+    let logins: LoginHistoryResp = LoginHistory::builder()
+        .limit(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(logins.len(), 2);
+    assert_eq!(logins[0].ip, "203.0.113.7");
+    assert_eq!(logins[0].time, 1680000000000);
+    assert_eq!(
+        logins[0].extra_info,
+        Some(json!({ "user_agent": "Mozilla/5.0" }))
+    );
+    assert_eq!(logins[1].extra_info, None);
+}
+
+#[test]
+fn margin_info() {
+    let client = client(
+        "v2/auth/r/info/margin/base",
+        include_str!("fixtures/authenticated/base_margin_info.json"),
+    )
+    .with(
+        MockResponse::new(Method::POST, "v2/auth/r/info/margin/tBTCUSD").data(include_str!(
+            "fixtures/authenticated/symbol_margin_info.json"
+        )),
+    )
+    .with(
+        MockResponse::new(Method::POST, "v2/auth/r/info/margin/sym_all").data(include_str!(
+            "fixtures/authenticated/all_symbols_margin_info.json"
+        )),
+    );
+
+    let base: BaseMarginInfoResp = BaseMarginInfo::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(base.user_pl, -13.01464);
+    assert_eq!(base.margin_balance, 49331.70267297);
+    assert_eq!(base.margin_min, 27.);
+
+    let sym: SymbolMarginInfoResp = SymbolMarginInfo::builder()
+        .symbol("tBTCUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(sym.symbol, "tBTCUSD");
+    assert_eq!(sym.gross_balance, 49331.70);
+
+    let all: AllSymbolsMarginInfoResp = AllSymbolsMarginInfo::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[1].symbol, "tETHUSD");
+    assert_eq!(all[1].sell, -1.5);
+}
+
+#[test]
+fn set_alert() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/alert/set")
+            .body(json!({ "type": "price", "symbol": "tBTCUSD", "price": "600" }))
+            .data(include_str!("fixtures/authenticated/set_alert.json")),
+    );
+
+    let alert: SetAlertResp = SetAlert::builder()
+        .symbol("tBTCUSD")
+        .price(600.)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(alert.key, "price:tBTCUSD:600");
+    assert_eq!(alert.price, 600.);
+}
+
+#[test]
+fn set_settings() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/settings/set")
+            .body(json!({
+                "settings": { "api:bot-config": { "max_position": 2.5 }, "api:theme": "dark" }
+            }))
+            .data(include_str!("fixtures/authenticated/set_settings.json")),
+    );
+
+    let rsp: SetSettingsResp = SetSettings::builder()
+        .settings(vec![
+            ("api:bot-config", json!({ "max_position": 2.5 })),
+            ("api:theme", json!("dark")),
+        ])
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(rsp.success);
+}
+
+#[test]
+fn settings() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/settings")
+            .body(json!({ "keys": ["api:bot-config", "api:theme"] }))
+            .data(include_str!("fixtures/authenticated/settings.json")),
+    );
+
+    let settings: SettingsResp = Settings::builder()
+        .keys(vec!["api:bot-config", "api:theme"])
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(settings.len(), 2);
+    assert_eq!(settings[0].key, "api:bot-config");
+    assert_eq!(settings[0].value["enabled"], json!(true));
+    assert_eq!(settings[1].value, json!("dark"));
+}
+
+#[test]
+fn summary() {
+    let client = client(
+        "v2/auth/r/summary",
+        include_str!("fixtures/authenticated/summary.json"),
+    );
+
+    let summary: SummaryResp = Summary::builder().build().unwrap().query(&client).unwrap();
+    assert_eq!(summary.maker_fee, 0.001);
+    assert_eq!(summary.deriv_rebate, -0.0002);
+    assert_eq!(summary.taker_fee_to_crypto, 0.002);
+    assert_eq!(summary.deriv_taker_fee, 0.00075);
+    assert_eq!(summary.trade_volume.len(), 2);
+    assert_eq!(summary.trade_volume[0].vol_maker, 0.5);
+    assert_eq!(summary.trade_volume[1].curr, "Total (USD)");
+    assert_eq!(summary.trade_volume[1].vol, 45000.);
+    assert_eq!(summary.trading_fees["USD"], 12.5);
+    assert_eq!(summary.trading_fees_total, 102.5);
+    assert_eq!(summary.funding_fees_total, 1.25);
+    assert_eq!(summary.leo_lev, 1);
+    assert_eq!(summary.leo_amount_avg, 1250.5);
+}
+
+#[test]
+fn user_info() {
+    let client = client(
+        "v2/auth/r/info/user",
+        include_str!("fixtures/authenticated/user_info.json"),
+    );
+
+    let info: UserInfoResp = UserInfo::builder().build().unwrap().query(&client).unwrap();
+    assert_eq!(info.id, 1234567);
+    assert_eq!(info.username, "satoshi");
+    assert!(info.verified);
+    assert_eq!(info.verification_level, 3);
+    assert_eq!(info.timezone.as_deref(), Some("Europe/Zurich"));
+    assert!(info.merchant_enabled);
+    assert!(!info.is_group_master);
+    assert_eq!(info.two_fa_modes, vec!["u2f", "otp"]);
+    assert!(!info.is_securities_investor_accredited);
+    assert!(info.allow_disable_ctxswitch);
+    assert!(!info.ctxswitch_disabled);
+    assert_eq!(info.time_last_login, Some(1680000000000));
+    assert_eq!(info.verification_level_submitted, Some(3));
+    assert_eq!(info.comp_countries, vec!["us", "ch"]);
+    assert_eq!(info.comp_countries_resid, vec!["ch"]);
+    assert_eq!(info.compl_account_type.as_deref(), Some("individual"));
+    assert!(!info.is_merchant_enterprise);
+}
+
+#[test]
+fn user_info_ignores_new_trailing_fields() {
+    let fixture = include_str!("fixtures/authenticated/user_info.json")
+        .trim_end()
+        .trim_end_matches(']');
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/info/user").data(format!("{fixture},null,1]")),
+    );
+
+    let info: UserInfoResp = UserInfo::builder().build().unwrap().query(&client).unwrap();
+    assert_eq!(info.id, 1234567);
+    assert_eq!(info.compl_account_type.as_deref(), Some("individual"));
+}
+
+#[test]
+fn active_funding_offers() {
+    let client = client(
+        "v2/auth/r/funding/offers/fUSD",
+        include_str!("fixtures/authenticated/active_funding_offers.json"),
+    );
+
+    let offers: ActiveFundingOffersResp = ActiveFundingOffers::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(offers.len(), 2);
+    assert_eq!(offers[0].id, 41238905);
+    assert_eq!(offers[0].offer_type, FundingOfferType::Limit);
+    assert_eq!(offers[0].rate, 0.003);
+    assert!(!offers[0].hidden);
+    assert_eq!(offers[1].offer_type, FundingOfferType::FrrDeltaVar);
+    assert_eq!(offers[1].offer_status, "PARTIALLY FILLED");
+    assert_eq!(offers[1].period, 30);
+    assert!(offers[1].hidden);
+    assert!(offers[1].renew);
+}
+
+#[test]
+fn cancel_all_funding_offers() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/funding/offer/cancel/all")
+            .body(json!({ "currency": "USD" }))
+            .data(include_str!(
+                "fixtures/authenticated/cancel_all_funding_offers.json"
+            )),
+    );
+
+    let rsp: CancelAllFundingOffersResp = CancelAllFundingOffers::builder()
+        .currency("USD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "foc_all-req");
+    assert_eq!(rsp.status, "SUCCESS");
+    assert_eq!(rsp.text, "All fUSD offers cancelled");
+}
+
+#[test]
+fn cancel_funding_offer() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/funding/offer/cancel")
+            .body(json!({ "id": 41238905 }))
+            .data(include_str!(
+                "fixtures/authenticated/cancel_funding_offer.json"
+            )),
+    );
+
+    let rsp: CancelFundingOfferResp = CancelFundingOffer::builder()
+        .id(41238905)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "foc-req");
+    assert_eq!(rsp.message_id, None);
+    assert_eq!(rsp.offer.id, 41238905);
+    assert_eq!(rsp.offer.amount, -100.);
+    assert_eq!(rsp.status, "SUCCESS");
+}
+
+#[test]
+fn close_funding() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/funding/close")
+            .body(json!({ "id": 13683223 }))
+            .data(include_str!("fixtures/authenticated/close_funding.json")),
+    );
+
+    let rsp: CloseFundingResp = CloseFunding::builder()
+        .id(13683223)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "fcl-req");
+    assert_eq!(rsp.status, "SUCCESS");
+    assert!(rsp.funding.is_none());
+}
+
+#[test]
+fn funding_credits() {
+    let client = client(
+        "v2/auth/r/funding/credits/fUSD",
+        include_str!("fixtures/authenticated/funding_credits.json"),
+    );
+
+    let credits: FundingCreditsResp = FundingCredits::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(credits.len(), 1);
+    assert_eq!(credits[0].id, 26222883);
+    assert_eq!(credits[0].side, LoanSide::Lender);
+    assert_eq!(credits[0].rate_type, RateType::Fixed);
+    assert_eq!(credits[0].rate, 0.0024);
+    assert_eq!(credits[0].notify, None);
+    assert!(!credits[0].no_close);
+    assert_eq!(credits[0].position_pair, "tBTCUSD");
+}
+
+#[test]
+fn funding_credits_history() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/funding/credits/fUSD/hist")
+            .body(json!({ "start": 1575491290000u64, "limit": 10 }))
+            .data(include_str!(
+                "fixtures/authenticated/funding_credits_history.json"
+            )),
+    );
+
+    let credits: FundingCreditsHistoryResp = FundingCreditsHistory::builder()
+        .symbol("fUSD")
+        .start(1575491290000)
+        .limit(10)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(credits.len(), 1);
+    assert_eq!(credits[0].status, "CLOSED (reduced)");
+    assert_eq!(credits[0].position_pair, "tBTCUSD");
+}
+
+#[test]
+fn funding_info() {
+    let client = client(
+        "v2/auth/r/info/funding/fUSD",
+        include_str!("fixtures/authenticated/funding_info.json"),
+    );
+
+    let info: FundingInfoResp = FundingInfo::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(info.symbol, "fUSD");
+    assert_eq!(info.yield_loan, 0.0001);
+    assert_eq!(info.yield_lend, 0.0002);
+    assert_eq!(info.duration_loan, 2.5);
+    assert_eq!(info.duration_lend, 30.);
+}
+
+#[test]
+fn funding_auto_renew() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/funding/auto")
+            .body(json!({
+                "status": 1,
+                "currency": "USD",
+                "amount": "1000",
+                "rate": "0.24",
+                "period": 2
+            }))
+            .data(include_str!(
+                "fixtures/authenticated/funding_auto_renew.json"
+            )),
+    );
+
+    let rsp: FundingAutoRenewResp = FundingAutoRenew::builder()
+        .currency("USD")
+        .active(true)
+        .amount(1000.)
+        .rate(0.24)
+        .period(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "fa-req");
+    let settings = rsp.settings.unwrap();
+    assert_eq!(settings.currency, "USD");
+    assert_eq!(settings.period, 2);
+    assert_eq!(settings.rate, 0.0024);
+    assert_eq!(settings.threshold, 1000.);
+}
+
+#[test]
+fn funding_loans() {
+    let client = client(
+        "v2/auth/r/funding/loans/fUSD",
+        include_str!("fixtures/authenticated/funding_loans.json"),
+    );
+
+    let loans: FundingLoansResp = FundingLoans::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(loans.len(), 1);
+    assert_eq!(loans[0].side, LoanSide::Borrower);
+    assert_eq!(loans[0].rate_type, RateType::Var);
+    assert_eq!(loans[0].amount, 50.);
+    assert!(loans[0].renew);
+    assert!(!loans[0].hidden);
+}
+
+#[test]
+fn funding_loans_history() {
+    let client = client(
+        "v2/auth/r/funding/loans/hist",
+        include_str!("fixtures/authenticated/funding_loans_history.json"),
+    );
+
+    let loans: FundingLoansHistoryResp = FundingLoansHistory::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(loans.len(), 1);
+    assert_eq!(loans[0].side, LoanSide::Borrower);
+    assert_eq!(loans[0].status, "CLOSED (used)");
+    assert_eq!(loans[0].mts_last_payout, 1575463921000);
+}
+
+#[test]
+fn funding_offers_history() {
+    let client = client(
+        "v2/auth/r/funding/offers/fUSD/hist",
+        include_str!("fixtures/authenticated/funding_offers_history.json"),
+    );
+
+    let offers: FundingOffersHistoryResp = FundingOffersHistory::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(offers.len(), 1);
+    assert_eq!(offers[0].amount_orig, -50.);
+    assert!(offers[0].offer_status.starts_with("EXECUTED"));
+}
+
+#[test]
+fn funding_trades() {
+    let client = client(
+        "v2/auth/r/funding/trades/fUSD/hist",
+        include_str!("fixtures/authenticated/funding_trades.json"),
+    );
+
+    let trades: FundingTradesResp = FundingTrades::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(trades.len(), 2);
+    assert_eq!(trades[0].offer_id, 41238905);
+    assert_eq!(trades[0].amount, -1000.);
+    assert_eq!(trades[0].rate, 0.002);
+    assert_eq!(trades[1].period, 2);
+}
+
+#[test]
+fn keep_funding() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/funding/keep")
+            .body(json!({
+                "type": "loan",
+                "id": 13683223,
+                "changes": { "13683223": 1, "13683224": 0 }
+            }))
+            .data(include_str!("fixtures/authenticated/keep_funding.json")),
+    );
+
+    let rsp: KeepFundingResp = KeepFunding::builder()
+        .ty(FundingKind::Loan)
+        .id(13683223)
+        .changes(vec![(13683223, true), (13683224, false)])
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "fk-req");
+    assert_eq!(rsp.text, "Funding keep updated");
+    let Some(LoanOrCredit::Loan(loan)) = rsp.funding else {
+        panic!("expected a loan, got {:?}", rsp.funding);
+    };
+    assert_eq!(loan.id, 13683223);
+    assert!(loan.no_close);
+}
+
+#[test]
+fn keep_funding_credit() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/funding/keep")
+            .body(json!({ "type": "credit", "id": 26222883 }))
+            .data(include_str!(
+                "fixtures/authenticated/keep_funding_credit.json"
+            )),
+    );
+
+    let rsp: KeepFundingResp = KeepFunding::builder()
+        .ty(FundingKind::Credit)
+        .id(26222883)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    let Some(LoanOrCredit::Credit(credit)) = rsp.funding else {
+        panic!("expected a credit, got {:?}", rsp.funding);
+    };
+    assert_eq!(credit.id, 26222883);
+    assert!(credit.no_close);
+    assert_eq!(credit.position_pair, "tBTCUSD");
+}
+
+#[test]
+fn submit_funding_offer() {
+    let client = client(
+        "v2/auth/w/funding/offer/submit",
+        include_str!("fixtures/authenticated/submit_funding_offer.json"),
+    );
+
+    let rsp: SubmitFundingOfferResp = SubmitFundingOffer::builder()
+        .ty(FundingOfferType::Limit)
+        .symbol("fUSD")
+        .amount(100.)
+        .rate(0.003)
+        .period(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "fon-req");
+    assert_eq!(rsp.offer.symbol, "fUSD");
+    assert_eq!(rsp.offer.period, 2);
+    assert_eq!(rsp.offer.flags, Some(0));
+    assert!(rsp.text.starts_with("Submitting funding offer"));
+}
+
+#[test]
+fn movements_history() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::POST, "v2/auth/r/movements/hist")
+                .body(json!({ "limit": 2 }))
+                .data(include_str!(
+                    "fixtures/authenticated/movements_history.json"
+                )),
+        )
+        .with(MockResponse::new(
+            Method::POST,
+            "v2/auth/r/movements/BTC/hist",
+        ));
+
+    let movements: MovementsHistoryResp = MovementsHistory::builder()
+        .limit(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(movements.len(), 2);
+    assert_eq!(movements[0].currency_name, "ETHEREUM");
+    assert_eq!(movements[0].status, "CANCELED");
+    assert_eq!(movements[0].amount, -0.24);
+    assert_eq!(movements[0].fees, -0.00135);
+    assert_eq!(movements[0].payment_id, None);
+    assert_eq!(
+        movements[0].withdraw_transaction_note.as_deref(),
+        Some("Purchase of 100 pizzas")
+    );
+    assert_eq!(movements[1].payment_id.as_deref(), Some("1207040"));
+
+    let movements: MovementsHistoryResp = MovementsHistory::builder()
+        .currency("BTC")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(movements.is_empty());
+}
+
+#[test]
+fn movement_info() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/movements/info")
+            .body(json!({ "id": 13105603 }))
+            .data(include_str!("fixtures/authenticated/movement_info.json")),
+    );
+
+    let info: MovementInfoResp = MovementInfo::builder()
+        .id(13105603)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(info.movement.id, 13105603);
+    assert_eq!(info.movement.amount, -1000.);
+    assert_eq!(info.movement.destination_address, None);
+    assert_eq!(info.bank_fees, Some(5.));
+    assert_eq!(info.bank_router_id, Some(102));
+    assert_eq!(info.external_bank_mov_status.as_deref(), Some("PROCESSED"));
+    assert_eq!(
+        info.external_bank_acc_info.unwrap()["bank_name"],
+        "Example Bank"
+    );
+}
+
+#[test]
+fn deposit_address() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/deposit/address")
+            .body(json!({ "wallet": "exchange", "method": "bitcoin", "op_renew": 0 }))
+            .data(include_str!("fixtures/authenticated/deposit_address.json")),
+    );
+
+    let rsp: GetDepositAddressResp = GetDepositAddress::builder()
+        .wallet(WalletType::Exchange)
+        .method(MovementMethod::Bitcoin)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "acc_dep");
+    assert_eq!(rsp.address.method, "BITCOIN");
+    assert_eq!(rsp.address.currency, "BTC");
+    assert_eq!(rsp.address.address, "1HAmGXjgjEK6Tn5LyTzmC4HA3NbHgJMfmC");
+    assert_eq!(rsp.address.pool_address, None);
+}
+
+#[test]
+fn deposit_addresses() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/deposit/address/all")
+            .body(json!({ "method": "bitcoin", "page": 1 }))
+            .data(include_str!(
+                "fixtures/authenticated/deposit_addresses.json"
+            )),
+    );
+
+    let addresses: DepositAddressesResp = DepositAddresses::builder()
+        .method(MovementMethod::Other("bitcoin"))
+        .page(1)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(addresses.len(), 2);
+    assert_eq!(
+        addresses[1].address,
+        "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"
+    );
+}
+
+#[test]
+fn deposit_invoice() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/deposit/invoice")
+            .body(json!({ "currency": "LNX", "wallet": "exchange", "amount": "0.00001" }))
+            .data(include_str!("fixtures/authenticated/deposit_invoice.json")),
+    );
+
+    let invoice: DepositInvoiceResp = DepositInvoice::builder()
+        .wallet(WalletType::Exchange)
+        .amount(0.00001)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(invoice.invoice.starts_with("lnbc"));
+    assert_eq!(invoice.amount, 0.00001);
+
+    for amount in [0., -0.00001, f64::NAN, f64::INFINITY] {
+        let invalid = DepositInvoice::builder()
+            .wallet(WalletType::Exchange)
+            .amount(amount)
+            .build();
+        assert!(invalid.is_err(), "{amount}");
+    }
+}
+
+#[test]
+fn withdraw() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/withdraw")
+            .body(json!({
+                "wallet": "exchange",
+                "method": "ethereum",
+                "amount": "0.01",
+                "address": "0x5a5f4a8e2d6d9a3c1a2d2b0e7f6e1a0c4b3d2e1f",
+                "fee_deduct": 0
+            }))
+            .data(include_str!("fixtures/authenticated/withdraw.json")),
+    );
+
+    let rsp: WithdrawResp = Withdraw::builder()
+        .wallet(WalletType::Exchange)
+        .method(MovementMethod::Ethereum)
+        .amount(0.01)
+        .address("0x5a5f4a8e2d6d9a3c1a2d2b0e7f6e1a0c4b3d2e1f")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "acc_wd-req");
+    assert_eq!(rsp.withdrawal.id, 13080092);
+    assert_eq!(rsp.withdrawal.method, "ethereum");
+    assert_eq!(rsp.withdrawal.wallet, Some(WalletType::Exchange));
+    assert_eq!(rsp.withdrawal.fee, 0.00135);
+
+    for amount in [0., -0.01, f64::NAN, f64::INFINITY] {
+        let invalid = Withdraw::builder()
+            .wallet(WalletType::Exchange)
+            .method(MovementMethod::Ethereum)
+            .amount(amount)
+            .address("0x5a5f4a8e2d6d9a3c1a2d2b0e7f6e1a0c4b3d2e1f")
+            .build();
+        assert!(invalid.is_err(), "{amount}");
+    }
+
+    let no_address = Withdraw::builder()
+        .wallet(WalletType::Exchange)
+        .method(MovementMethod::Ethereum)
+        .amount(0.01)
+        .address(" ")
+        .build();
+    assert!(no_address.is_err());
+}
+
+#[test]
+fn cancel_order() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/order/cancel")
+            .body(json!({ "id": 30630788061u64 }))
+            .data(include_str!("fixtures/authenticated/cancel_order.json")),
+    );
+
+    let rsp: CancelOrderResp = CancelOrder::builder()
+        .id(30630788061)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "oc-req");
+    assert_eq!(rsp.order.id, 30630788061);
+    assert_eq!(rsp.order.order_type, OrderType::Limit);
+    assert_eq!(rsp.order.flags, Some(OrderFlag::PostOnly as u64));
+    assert_eq!(rsp.order.routing, "API>BFX");
+}
+
+#[test]
+fn cancel_orders() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/order/cancel")
+            .body(json!({ "id": [30630788061u64, 30630788062u64] }))
+            .data(include_str!("fixtures/authenticated/cancel_orders.json")),
+    );
+
+    let rsp: CancelOrdersResp = CancelOrders::builder()
+        .cancel_orders_type(CancelOrdersType::OnlyIds(vec![30630788061, 30630788062]))
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "oc_multi-req");
+    assert_eq!(rsp.message_id, None);
+    assert_eq!(rsp.orders.len(), 2);
+    assert_eq!(rsp.orders[1].symbol, "tETHUSD");
+    assert_eq!(rsp.code, None);
+}
+
+#[test]
+fn order_multi_op() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/order/multi")
+            .body(json!({
+                "ops": [
+                    ["on", { "type": "LIMIT", "symbol": "tETHUSD", "amount": "0.1", "price": "150" }],
+                    ["oc", { "id": 30630788061u64 }],
+                    ["ou", { "id": 30630788063u64, "price": "16" }]
+                ]
+            }))
+            .data(include_str!("fixtures/authenticated/order_multi_op.json")),
+    );
+
+    let submit = SubmitOrder::builder()
+        .ty(OrderType::Limit)
+        .symbol("tETHUSD")
+        .amount(0.1)
+        .price(150.)
+        .build()
+        .unwrap();
+    let cancel = CancelOrder::builder().id(30630788061).build().unwrap();
+    let update = UpdateOrder::builder()
+        .id(30630788063)
+        .price(16.)
+        .build()
+        .unwrap();
+
+    let rsp: OrderMultiOpResp = OrderMultiOp::builder()
+        .op(OrderOp::Submit(&submit))
+        .op(OrderOp::Cancel(&cancel))
+        .op(OrderOp::Update(&update))
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "ox_multi-req");
+    assert_eq!(rsp.results.len(), 3);
+    assert_eq!(rsp.results[0].ty, "on-req");
+    assert_eq!(rsp.results[0].orders[0].symbol, "tETHUSD");
+    assert_eq!(rsp.results[1].orders[0].id, 30630788061);
+    assert_eq!(rsp.results[2].status, "ERROR");
+    assert!(rsp.results[2].orders.is_empty());
+}
+
+#[test]
+fn order_multi_op_validation() {
+    let cancel = CancelOrder::builder().id(30630788061).build().unwrap();
+
+    assert!(matches!(
+        OrderMultiOp::builder().ops(vec![]).build(),
+        Err(OrderMultiOpBuilderError::ValidationError(_))
+    ));
+    assert!(matches!(
+        OrderMultiOp::builder()
+            .ops(vec![OrderOp::Cancel(&cancel); 76])
+            .build(),
+        Err(OrderMultiOpBuilderError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn order_trades() {
+    let client = client(
+        "v2/auth/r/order/tBTCUSD:36045066045/trades",
+        include_str!("fixtures/authenticated/order_trades.json"),
+    );
+
+    let trades: OrderTradesResp = OrderTrades::builder()
+        .symbol("tBTCUSD")
+        .id(36045066045)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(trades.len(), 2);
+    assert!(trades.iter().all(|trade| trade.order_id == 36045066045));
+    assert_eq!(trades[1].exec_price, 7348.1);
+    assert_eq!(trades[1].maker, 1);
+    assert_eq!(trades[0].fee_currency, "BTC");
+}
+
+#[test]
+fn orders_history() {
+    let client = client(
+        "v2/auth/r/orders/hist",
+        include_str!("fixtures/authenticated/orders_history.json"),
+    );
+
+    let history: OrdersHistoryResp = OrdersHistory::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    let order = &history.orders[0];
+    assert_eq!(order.gid, Some(1));
+    assert_eq!(order.order_type, OrderType::ExchangeLimit);
+    assert_eq!(order.status, "EXECUTED @ 175.5(-0.5)");
+    assert_eq!(order.amount_orig, -0.5);
+    assert_eq!(order.price_avg, 175.5);
+    assert!(order.hidden);
+    assert_eq!(order.meta, Some(json!({ "aff_code": "abc" })));
+}
+
+#[test]
+fn retrieve_orders() {
+    let client = client(
+        "v2/auth/r/orders",
+        include_str!("fixtures/authenticated/retrieve_orders.json"),
+    );
+
+    let orders: RetrieveOrdersResp = RetrieveOrders::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].cid, 1567590617439);
+    assert_eq!(orders[0].status, "ACTIVE");
+    assert_eq!(orders[0].price, 15.);
+    assert!(!orders[0].notify);
+}
+
+#[test]
+fn retrieve_orders_by_symbol() {
+    let client = client(
+        "v2/auth/r/orders/tBTCUSD",
+        include_str!("fixtures/authenticated/retrieve_orders.json"),
+    );
+
+    let orders: RetrieveOrdersBySymbolResp = RetrieveOrdersBySymbol::builder()
+        .symbol("tBTCUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(orders[0].symbol, "tBTCUSD");
+    assert_eq!(orders[0].amount, 0.001);
+}
+
+#[tokio::test]
+async fn submit_order() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/order/submit")
+            .body(json!({
+                "type": "LIMIT",
+                "symbol": "tBTCUSD",
+                "amount": "0.001",
+                "price": "15",
+                "flags": 4096
+            }))
+            .data(include_str!("fixtures/authenticated/submit_order.json")),
+    );
+
+    let rsp: SubmitOrderResp = SubmitOrder::builder()
+        .ty(OrderType::Limit)
+        .symbol("tBTCUSD")
+        .amount(0.001)
+        .price(15.)
+        .flags(vec![OrderFlag::PostOnly])
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+    assert_eq!(rsp.ty, "on-req");
+    assert_eq!(rsp.message_id, Some(1235));
+    assert_eq!(rsp.order.id, 30630788061);
+    assert_eq!(rsp.status, "SUCCESS");
+}
+
+#[test]
+fn update_order() {
+    let client = client(
+        "v2/auth/w/order/update",
+        include_str!("fixtures/authenticated/update_order.json"),
+    );
+
+    let rsp: UpdateOrderResp = UpdateOrder::builder()
+        .id(30630788061)
+        .amount(0.001)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "ou-req");
+    assert_eq!(rsp.order.mts_updated, 1567590617441);
+    assert!(rsp.text.starts_with("Submitting update"));
+}
+
+#[test]
+fn active_positions() {
+    let client = client(
+        "v2/auth/r/positions",
+        include_str!("fixtures/authenticated/positions.json"),
+    );
+
+    let positions: ActivePositionsResp = ActivePositions::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions[0].symbol, "tETHUST");
+    assert_eq!(positions[0].margin_funding_type, MarginFundingType::Daily);
+    assert_eq!(positions[0].price_liq, Some(67.52755254906451));
+    assert_eq!(positions[0].ty, Some(PositionType::Margin));
+    assert_eq!(positions[0].meta.as_ref().unwrap()["reason"], "TRADE");
+    assert_eq!(positions[1].margin_funding_type, MarginFundingType::Term);
+    assert_eq!(positions[1].leverage, Some(10.));
+    assert_eq!(positions[1].ty, Some(PositionType::Derivatives));
+    assert_eq!(positions[1].collateral, Some(52.));
+    assert_eq!(positions[1].collateral_min, Some(2.6));
+}
+
+#[test]
+fn positions_history() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/positions/hist")
+            .body(json!({ "start": 1574963975000u64, "limit": 2 }))
+            .data(include_str!(
+                "fixtures/authenticated/positions_history.json"
+            )),
+    );
+
+    let positions: PositionsHistoryResp = PositionsHistory::builder()
+        .start(1574963975000)
+        .limit(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions[0].status, "CLOSED");
+    assert_eq!(positions[0].position_id, 142420429);
+    assert_eq!(positions[0].pl, None);
+    assert_eq!(positions[0].mts_update, Some(1574963976000));
+    assert_eq!(positions[0].ty, None);
+    assert_eq!(positions[0].meta, None);
+}
+
+#[test]
+fn positions_snapshot() {
+    let client = client(
+        "v2/auth/r/positions/snap",
+        include_str!("fixtures/authenticated/positions_snapshot.json"),
+    );
+
+    let positions: PositionsSnapshotResp = PositionsSnapshot::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(positions[0].status, "ACTIVE");
+    assert_eq!(positions[0].amount, 0.5);
+    assert_eq!(positions[1].margin_funding, 0.0005);
+}
+
+#[test]
+fn positions_audit() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/positions/audit")
+            .body(json!({ "id": [142420429] }))
+            .data(include_str!("fixtures/authenticated/positions_audit.json")),
+    );
+
+    let positions: PositionsAuditResp = PositionsAudit::builder()
+        .id(vec![142420429])
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].position_id, 142420429);
+    assert_eq!(positions[0].collateral, Some(0.));
+    assert_eq!(positions[0].meta.as_ref().unwrap()["trade_price"], "153.71");
+}
+
+#[test]
+fn claim_position() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/position/claim")
+            .body(json!({ "id": 142031891, "amount": "0.2" }))
+            .data(include_str!("fixtures/authenticated/claim_position.json")),
+    );
+
+    let rsp: ClaimPositionResp = ClaimPosition::builder()
+        .id(142031891)
+        .amount(0.2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "pos_claim");
+    assert_eq!(rsp.position.position_id, 142031891);
+    assert_eq!(rsp.position.amount, -0.2);
+    assert_eq!(rsp.position.ty, None);
+    assert_eq!(rsp.status, "SUCCESS");
+}
+
+#[test]
+fn increase_position() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/position/increase")
+            .body(json!({ "symbol": "tBTCUSD", "amount": "0.00001" }))
+            .data(include_str!(
+                "fixtures/authenticated/increase_position.json"
+            )),
+    );
+
+    let rsp: IncreasePositionResp = IncreasePosition::builder()
+        .symbol("tBTCUSD")
+        .amount(0.00001)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "pos_inc");
+    assert_eq!(rsp.position.symbol, "tBTCUSD");
+    assert_eq!(rsp.position.amount, 0.00001);
+    assert_eq!(rsp.position.base_price, 8882.8);
+}
+
+#[test]
+fn increase_position_info() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/position/increase/info")
+            .body(json!({ "symbol": "tBTCUSD", "amount": "0.1" }))
+            .data(include_str!(
+                "fixtures/authenticated/increase_position_info.json"
+            )),
+    );
+
+    let info: IncreasePositionInfoResp = IncreasePositionInfo::builder()
+        .symbol("tBTCUSD")
+        .amount(0.1)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(info.max_pos, 5.5);
+    assert_eq!(info.current_pos, Some(0.));
+    assert_eq!(info.tradable_balance_quote_total, 49841.2);
+    assert_eq!(info.funding_avail, 0.4);
+    assert_eq!(info.funding_required, 0.1);
+    assert_eq!(info.funding_value_currency, "USD");
+    assert_eq!(info.funding_required_currency, "BTC");
+}
+
+#[test]
+fn pulse_history() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/r/pulse/hist")
+            .body(json!({ "isPublic": 1 }))
+            .data(include_str!("fixtures/authenticated/pulse_history.json")),
+    );
+
+    let pulses: PulseHistoryResp = PulseHistory::builder()
+        .is_public(true)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(pulses.len(), 1);
+    assert_eq!(pulses[0].puid, "d6d0ed0e-0a2f-4a8e-9c5a-2ae1c0e6b7f8");
+}
+
+#[test]
+fn add_pulse() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/pulse/add")
+            .body(json!({
+                "title": "Rebalancing the book",
+                "content": "Moving funding to fUSD this week",
+                "isPublic": 1,
+                "isPin": 1
+            }))
+            .data(include_str!("fixtures/authenticated/add_pulse.json")),
+    );
+
+    let pulse: AddPulseResp = AddPulse::builder()
+        .title("Rebalancing the book")
+        .content("Moving funding to fUSD this week")
+        .is_pin(true)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(pulse.pid, "0f1e2d3c-4b5a-6978-8a9b-0c1d2e3f4a5b");
+    assert!(pulse.is_pin);
+    assert!(pulse.profile.is_none());
+    assert_eq!(pulse.comments, 0);
+}
+
+#[test]
+fn delete_pulse() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/pulse/del")
+            .body(json!({ "pid": "0f1e2d3c-4b5a-6978-8a9b-0c1d2e3f4a5b" }))
+            .data(include_str!("fixtures/authenticated/delete_pulse.json")),
+    );
+
+    let rsp: DeletePulseResp = DeletePulse::builder()
+        .pid("0f1e2d3c-4b5a-6978-8a9b-0c1d2e3f4a5b")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(rsp.success);
+}
+
+#[test]
+fn trades() {
+    let client = client(
+        "v2/auth/r/trades/hist",
+        include_str!("fixtures/authenticated/trades.json"),
+    );
+
+    let trades: TradesResp = Trades::builder()
+        .limit(1)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].symbol, "tETHUST");
+    assert_eq!(trades[0].exec_amount, -0.2);
+    assert_eq!(trades[0].order_type, OrderType::Market);
+    assert_eq!(trades[0].maker, -1);
+    assert_eq!(trades[0].fee_currency, "USD");
+}
+
+#[test]
+fn trades_by_symbol() {
+    let client = client(
+        "v2/auth/r/trades/tETHUST/hist",
+        include_str!("fixtures/authenticated/trades.json"),
+    );
+
+    let trades: TradesResp = Trades::builder()
+        .symbol("tETHUST")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(trades.len(), 1);
+    assert_eq!(trades[0].symbol, "tETHUST");
+}
+
+#[test]
+fn transfer() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/auth/w/transfer")
+            .body(json!({
+                "from": "exchange",
+                "to": "margin",
+                "currency": "UST",
+                "currency_to": "USTF0",
+                "amount": "50"
+            }))
+            .data(include_str!("fixtures/authenticated/transfer.json")),
+    );
+
+    let rsp: TransferResp = Transfer::builder()
+        .from(WalletType::Exchange)
+        .to(WalletType::Margin)
+        .currency("UST")
+        .currency_to("USTF0")
+        .amount(50.)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.ty, "acc_tf");
+    assert_eq!(rsp.transfer.wallet_from, WalletType::Exchange);
+    assert_eq!(rsp.transfer.wallet_to, WalletType::Margin);
+    assert_eq!(rsp.transfer.currency_to.as_deref(), Some("USTF0"));
+    assert_eq!(rsp.transfer.amount, 50.);
+
+    let same_wallet = Transfer::builder()
+        .from(WalletType::Exchange)
+        .to(WalletType::Exchange)
+        .currency("USD")
+        .amount(10.)
+        .build();
+    assert!(matches!(
+        same_wallet,
+        Err(TransferBuilderError::ValidationError(_))
+    ));
+
+    let sub_account = Transfer::builder()
+        .from(WalletType::Exchange)
+        .to(WalletType::Exchange)
+        .currency("USD")
+        .amount(10.)
+        .email_dst("sub-account@example.com")
+        .build();
+    assert!(sub_account.is_ok());
+
+    let negative = Transfer::builder()
+        .from(WalletType::Exchange)
+        .to(WalletType::Funding)
+        .currency("USD")
+        .amount(-10.)
+        .build();
+    assert!(negative.is_err());
+
+    for amount in [f64::NAN, f64::INFINITY] {
+        let invalid = Transfer::builder()
+            .from(WalletType::Exchange)
+            .to(WalletType::Funding)
+            .currency("USD")
+            .amount(amount)
+            .build();
+        assert!(invalid.is_err(), "{amount}");
+    }
+
+    // Only the wallets Bitfinex accepts can be used, an unknown wallet never makes it into a request.
+    assert!(serde_json::from_value::<WalletType>(json!("contribution")).is_err());
+    assert!(serde_json::from_value::<WalletType>(json!("unknown")).is_err());
+}
+
+#[test]
+fn wallets() {
+    let client = client(
+        "v2/auth/r/wallets",
+        include_str!("fixtures/authenticated/wallets.json"),
+    );
+
+    let wallets: WalletsResp = Wallets::builder().build().unwrap().query(&client).unwrap();
+    assert_eq!(wallets.len(), 2);
+    assert_eq!(wallets[0].ty, WalletType::Exchange);
+    assert_eq!(wallets[0].available_balance, 19788.6529257);
+    assert_eq!(
+        wallets[0].trade_details.as_ref().unwrap()["reason"],
+        "TRADE"
+    );
+    assert_eq!(wallets[1].ty, WalletType::Margin);
+    assert_eq!(wallets[1].last_change, None);
+}
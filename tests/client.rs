@@ -0,0 +1,175 @@
+mod common;
+
+use std::time::Duration;
+
+use bitfinex_api::{
+    api::{
+        authenticated::{
+            orders::{
+                submit_order::{SubmitOrder, SubmitOrderResp},
+                types::OrderType,
+            },
+            wallets::{Wallets, WalletsResp},
+        },
+        error::{ApiError, ServerError},
+        public::{
+            platform_status::{PlatformStatus, PlatformStatusResp},
+            ticker::{Ticker, TickerResp},
+        },
+        query::AsyncQuery,
+    },
+    bitfinex::{AsyncBitfinex, RestError},
+    rate_limit::{RateLimit, RateLimitMode, RateLimiter},
+    retry::RetryPolicy,
+};
+
+use common::serve_http;
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy::builder()
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn builder_configures_the_client() {
+    let (url, received) = serve_http(vec![(502, "Bad Gateway"), (200, "[1]")]).await;
+
+    let client = AsyncBitfinex::builder()
+        .pub_rest_url(&format!("{url}/gateway"))
+        .user_agent("bitfinex-api-tests")
+        .header("x-test", "1")
+        .timeout(Duration::from_secs(5))
+        .retry_policy(retry_policy())
+        .build_async()
+        .unwrap();
+
+    let status: PlatformStatusResp = PlatformStatus::builder()
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+    assert!(matches!(status, PlatformStatusResp::Operative));
+
+    let received = received.await.unwrap();
+    assert_eq!(received.len(), 2);
+    assert!(received[1].starts_with("GET /gateway/v2/platform/status"));
+    assert!(received[1].contains("user-agent: bitfinex-api-tests"));
+    assert!(received[1].contains("x-test: 1"));
+}
+
+#[tokio::test]
+async fn writes_are_not_retried_blindly() {
+    let (url, received) = serve_http(vec![(502, "Bad Gateway")]).await;
+
+    let client = AsyncBitfinex::builder()
+        .authenticated_rest_url(&url)
+        .auth("api-key", "secret-key")
+        .retry_policy(retry_policy())
+        .build_async()
+        .unwrap();
+
+    let order = SubmitOrder::builder()
+        .ty(OrderType::ExchangeLimit)
+        .symbol("tBTCUSD")
+        .price(26000.)
+        .amount(0.1)
+        .build()
+        .unwrap();
+    let rsp: Result<SubmitOrderResp, _> = order.query_async(&client).await;
+    assert!(matches!(
+        rsp,
+        Err(ApiError::ServerError {
+            source: ServerError::InvalidJson { .. },
+            ..
+        })
+    ));
+
+    let received = received.await.unwrap();
+    assert_eq!(received.len(), 1);
+    assert!(received[0].starts_with("POST /v2/auth/w/order/submit"));
+    assert!(received[0].contains("bfx-apikey: api-key"));
+}
+
+#[tokio::test]
+async fn rate_limits_apply_to_the_endpoint_path() {
+    let (url, received) = serve_http(vec![(
+        200,
+        "[26000,10.5,26001,8.2,-100,-0.0038,26000.5,1200.3,26300,25800]",
+    )])
+    .await;
+
+    let limiter = RateLimiter::new(RateLimitMode::FailFast).with_limit(
+        "v2/ticker/*",
+        RateLimit {
+            requests: 1,
+            period: Duration::from_secs(60),
+        },
+    );
+    let client = AsyncBitfinex::builder()
+        .pub_rest_url(&url)
+        .rate_limiter(limiter)
+        .build_async()
+        .unwrap();
+
+    let ticker = |symbol| Ticker::builder().symbol(symbol).build().unwrap();
+    let _: TickerResp = ticker("tBTCUSD").query_async(&client).await.unwrap();
+
+    // The symbols share the bucket of the `v2/ticker/*` pattern.
+    let rsp: Result<TickerResp, _> = ticker("tETHUSD").query_async(&client).await;
+    match rsp {
+        Err(ApiError::Client {
+            source: RestError::RateLimited { source },
+        }) => assert_eq!(source.pattern, "v2/ticker/*"),
+        rsp => panic!("unexpected response {rsp:?}"),
+    }
+
+    assert_eq!(received.await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn prefixed_base_urls_are_rate_limited() {
+    let (url, received) = serve_http(vec![(200, "[]")]).await;
+
+    let limiter = RateLimiter::new(RateLimitMode::FailFast).with_limit(
+        "v2/auth/r/wallets",
+        RateLimit {
+            requests: 1,
+            period: Duration::from_secs(60),
+        },
+    );
+    let client = AsyncBitfinex::builder()
+        .authenticated_rest_url(&format!("{url}/gateway/bitfinex"))
+        .auth("api-key", "secret-key")
+        .rate_limiter(limiter)
+        .build_async()
+        .unwrap();
+
+    let wallets: WalletsResp = Wallets::builder()
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+    assert!(wallets.is_empty());
+
+    let rsp: Result<WalletsResp, _> = Wallets::builder()
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await;
+    assert!(matches!(
+        rsp,
+        Err(ApiError::Client {
+            source: RestError::RateLimited { .. }
+        })
+    ));
+
+    let received = received.await.unwrap();
+    assert_eq!(received.len(), 1);
+    assert!(received[0].starts_with("POST /gateway/bitfinex/v2/auth/r/wallets"));
+    assert!(received[0].contains("bfx-signature: "));
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+    task::JoinHandle,
+};
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+/// Spawns a stand-in server answering each received message with the next batch of frames.
+///
+/// The handle resolves to the messages received by the server.
+pub async fn serve(script: Vec<Vec<&'static str>>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        ws.send(Message::Text(
+            r#"{"event":"info","version":2,"platform":{"status":1}}"#.into(),
+        ))
+        .await
+        .unwrap();
+
+        let mut received = Vec::new();
+        for frames in script {
+            received.push(ws.next().await.unwrap().unwrap().into_text().unwrap());
+            for frame in frames {
+                ws.send(Message::Text(frame.into())).await.unwrap();
+            }
+        }
+
+        received
+    });
+
+    (format!("ws://{addr}"), handle)
+}
+
+/// Spawns a stand-in HTTP server answering each request with the next `(status, body)` response.
+///
+/// The handle resolves to the received requests, without their body.
+pub async fn serve_http(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+        let mut received = Vec::new();
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                stream.read_line(&mut line).await.unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            let mut body_received = vec![0; content_length];
+            stream.read_exact(&mut body_received).await.unwrap();
+            received.push(request);
+
+            let response = format!(
+                "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        }
+
+        received
+    });
+
+    (format!("http://{addr}"), handle)
+}
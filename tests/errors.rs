@@ -0,0 +1,64 @@
+use bitfinex_api::api::error::{BitfinexError, ErrorCode, ServerError};
+use http::StatusCode;
+use serde_json::json;
+
+#[test]
+fn bitfinex_error_decoding() {
+    let error = BitfinexError::from_value(&json!(["error", 10020, "symbol: invalid"])).unwrap();
+    assert_eq!(error.code, ErrorCode::Params);
+    assert_eq!(error.message, "symbol: invalid");
+    assert_eq!(error.to_string(), "Bitfinex error 10020: symbol: invalid");
+
+    let error = BitfinexError::from_value(&json!({ "error": "ERR_RATE_LIMIT" })).unwrap();
+    assert_eq!(error.code, ErrorCode::RateLimit);
+
+    assert_eq!(ErrorCode::from(10114), ErrorCode::NonceSmall);
+    assert_eq!(ErrorCode::from(12345), ErrorCode::Other(12345));
+    assert_eq!(ErrorCode::Other(12345).code(), 12345);
+    assert_eq!(BitfinexError::from_value(&json!([1, 2, 3])), None);
+
+    match ServerError::from_response(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        json!(["error", 10100, "apikey: invalid"]),
+    ) {
+        Err(ServerError::Bitfinex { source }) => assert_eq!(source.code, ErrorCode::AuthFail),
+        rsp => panic!("unexpected response {rsp:?}"),
+    }
+}
+
+#[test]
+fn failed_notifications() {
+    let failed = json!([
+        1567590617442u64,
+        "on-req",
+        null,
+        null,
+        [],
+        null,
+        "ERROR",
+        "Invalid order: not enough exchange balance for 10 BTCUSD at 8200"
+    ]);
+    match ServerError::from_response(StatusCode::OK, failed) {
+        Err(ServerError::Notification { status, text, .. }) => {
+            assert_eq!(status, "ERROR");
+            assert!(text.starts_with("Invalid order"));
+        }
+        rsp => panic!("unexpected response {rsp:?}"),
+    }
+
+    let success = json!([
+        1567590617442u64,
+        "oc-req",
+        null,
+        null,
+        [],
+        null,
+        "SUCCESS",
+        "Submitted for cancellation"
+    ]);
+    assert!(ServerError::from_response(StatusCode::OK, success).is_ok());
+
+    // Data that only looks like a notification is left untouched.
+    let ticker = json!([26000, 10.5, 26001, 8.2, -100, -0.0038, 26000.5, 1200.3]);
+    assert!(ServerError::from_response(StatusCode::OK, ticker).is_ok());
+}
@@ -0,0 +1,103 @@
+use std::{collections::HashSet, fs, sync::Arc, thread};
+
+use bitfinex_api::{
+    auth::Auth,
+    nonce::{AtomicNonce, FileNonce, NonceSource},
+};
+use http::HeaderMap;
+
+#[test]
+fn atomic_nonces_are_unique_and_increasing() {
+    let source = Arc::new(AtomicNonce::new());
+
+    let handles = (0..4)
+        .map(|_| {
+            let source = source.clone();
+            thread::spawn(move || {
+                let nonces = (0..1000)
+                    .map(|_| source.next_nonce().unwrap())
+                    .collect::<Vec<_>>();
+                assert!(nonces.windows(2).all(|w| w[0] < w[1]));
+                nonces
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let nonces = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect::<HashSet<_>>();
+    assert_eq!(nonces.len(), 4000);
+
+    let ahead = AtomicNonce::starting_after(u64::MAX / 2);
+    assert_eq!(ahead.next_nonce().unwrap(), u64::MAX / 2 + 1);
+}
+
+#[test]
+fn auths_with_the_same_key_share_their_nonces() {
+    assert!(Arc::ptr_eq(
+        &AtomicNonce::shared("shared-key"),
+        &AtomicNonce::shared("shared-key")
+    ));
+
+    let first = Auth::new("shared-key".into(), "secret".into());
+    let second = Auth::new("shared-key".into(), "secret".into());
+
+    let nonce = |auth: &Auth| {
+        let mut headers = HeaderMap::new();
+        auth.set_headers(&mut headers, "v2/auth/r/wallets", b"{}")
+            .unwrap();
+        headers["bfx-nonce"]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap()
+    };
+
+    let mut last = 0;
+    for _ in 0..100 {
+        for auth in [&first, &second] {
+            let next = nonce(auth);
+            assert!(next > last);
+            last = next;
+        }
+    }
+}
+
+#[test]
+fn file_nonces_survive_restarts() {
+    let path = std::env::temp_dir().join(format!("bitfinex-nonce-{}", std::process::id()));
+    fs::write(&path, (u64::MAX / 2).to_string()).unwrap();
+
+    let source = FileNonce::open(&path).unwrap();
+    assert_eq!(source.next_nonce().unwrap(), u64::MAX / 2 + 1);
+    let last = source.next_nonce().unwrap();
+    drop(source);
+
+    let source = FileNonce::open(&path).unwrap();
+    assert_eq!(source.next_nonce().unwrap(), last + 1);
+
+    fs::write(&path, "not a nonce").unwrap();
+    assert!(FileNonce::open(&path).is_err());
+
+    // A file left empty is the same as no file.
+    fs::write(&path, "").unwrap();
+    let source = FileNonce::open(&path).unwrap();
+    assert!(source.next_nonce().unwrap() > 0);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_nonce_write_errors_are_returned() {
+    let path = std::env::temp_dir().join(format!("bitfinex-nonce-err-{}", std::process::id()));
+    let source = FileNonce::open(&path).unwrap();
+
+    // The nonce file can no longer be replaced once a directory takes its place.
+    fs::remove_file(&path).unwrap();
+    fs::create_dir(&path).unwrap();
+    assert!(source.next_nonce().is_err());
+
+    fs::remove_dir(&path).unwrap();
+    let _ = fs::remove_file(path.with_extension("tmp"));
+}
@@ -0,0 +1,641 @@
+use bitfinex_api::{
+    api::{
+        common::{Section, Sort, Symbol, Symbols, TimeFrame},
+        public::{
+            book::{
+                book::{Book, BookResp, Precision},
+                common::Len,
+                raw_book::{RawBook, RawBookResp},
+            },
+            candles::{AvailableCandles, Candles, HistCandlesResp, LastCandlesResp},
+            conf::{
+                ConfList, ConfListKey, ConfListResp, ConfMap, ConfMapKey, ConfMapResp,
+                ConfPairInfo, ConfPairInfoKey, ConfPairInfoResp,
+            },
+            derivative_status::{DerivativesStatus, DerivativesStatusResp},
+            derivative_status_history::{DerivativesStatusHistory, DerivativesStatusHistoryResp},
+            foreign_exchange_rate::{ForeignExchangeRate, ForeignExchangeRateResp},
+            funding_statistics::{FundingStatistics, FundingStatisticsResp},
+            leaderboards::{HistLeaderBoardsResp, Key, Leaderboards},
+            liquidations::{Liquidations, LiquidationsResp},
+            market_average_price::{MarketAveragePrice, MarketAveragePriceResp},
+            platform_status::{PlatformStatus, PlatformStatusResp},
+            pulse::{
+                pulse_history::{PulseHistory, PulseHistoryResp},
+                pulse_profile::{PulseProfileDetails, PulseProfileDetailsResp},
+            },
+            stats::{HistStatsResp, KeyArgs, LastStatsResp, Side, Stats},
+            ticker::{Ticker, TickerResp},
+            tickers::{self, Tickers, TickersResp},
+            tickers_history::{TickersHistory, TickersHistoryResp},
+            trades::{Trades, TradesResp},
+        },
+        query::{AsyncQuery, Query},
+    },
+    mock::{MockClient, MockResponse},
+};
+use http::Method;
+use serde_json::json;
+
+fn client(endpoint: &str, fixture: &'static str) -> MockClient {
+    MockClient::new().with(MockResponse::new(Method::GET, endpoint).data(fixture))
+}
+
+#[test]
+fn platform_status() {
+    let client = client(
+        "v2/platform/status",
+        include_str!("fixtures/public/platform_status.json"),
+    );
+
+    let status: PlatformStatusResp = PlatformStatus::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert!(matches!(status, PlatformStatusResp::Operative));
+    assert!(!client.requests()[0].is_authenticated);
+}
+
+#[test]
+fn conf() {
+    let client = client(
+        "v2/conf/pub:list:pair:exchange",
+        include_str!("fixtures/public/conf_list.json"),
+    )
+    .with(
+        MockResponse::new(Method::GET, "v2/conf/pub:map:currency:label")
+            .data(include_str!("fixtures/public/conf_map.json")),
+    )
+    .with(
+        MockResponse::new(Method::GET, "v2/conf/pub:info:pair")
+            .data(include_str!("fixtures/public/conf_pair_info.json")),
+    );
+
+    let pairs: ConfListResp = ConfList::builder()
+        .key(ConfListKey::ExchangePairs)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(pairs.values.len(), 6);
+    assert!(pairs.values.contains(&"BTCUSD".to_string()));
+
+    let labels: ConfMapResp = ConfMap::builder()
+        .key(ConfMapKey::CurrencyLabels)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(labels.values["UST"], "Tether USDt");
+
+    let info: ConfPairInfoResp = ConfPairInfo::builder()
+        .key(ConfPairInfoKey::Pairs)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(info.pairs.len(), 3);
+    let btcusd = info.get("tBTCUSD").unwrap();
+    assert_eq!(btcusd.min_order_size, 0.00004);
+    assert_eq!(btcusd.max_order_size, 2000.);
+    assert_eq!(btcusd.initial_margin, Some(0.2));
+    assert!(btcusd.is_valid_amount(-0.5));
+    assert!(!btcusd.is_valid_amount(0.00001));
+    assert_eq!(info.get("BTCEUR").unwrap().min_margin, None);
+}
+
+#[test]
+fn book() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::GET, "v2/book/tBTCUSD/P0")
+                .params(&[("len", "25")])
+                .data(include_str!("fixtures/public/book_trading.json")),
+        )
+        .with(
+            MockResponse::new(Method::GET, "v2/book/fUSD/P1")
+                .data(include_str!("fixtures/public/book_funding.json")),
+        );
+
+    let book: BookResp = Book::builder()
+        .symbol("tBTCUSD")
+        .precision(Precision::P0)
+        .len(Len::TwentyFive)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    match book {
+        BookResp::BookTradingResp(levels) => {
+            assert_eq!(levels.len(), 4);
+            assert_eq!(levels[0].price, 26000.);
+            assert_eq!(levels[0].count, 2);
+            assert_eq!(levels[2].amount, -0.75);
+        }
+        book => panic!("unexpected book {book:?}"),
+    }
+
+    let book: BookResp = Book::builder()
+        .symbol("fUSD")
+        .precision(Precision::P1)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    match book {
+        BookResp::BookFundingResp(levels) => {
+            assert_eq!(levels.len(), 3);
+            assert_eq!(levels[1].rate, 0.00021);
+            assert_eq!(levels[1].period, 30);
+            assert_eq!(levels[1].count, 1);
+            assert_eq!(levels[1].amount, -2000.5);
+        }
+        book => panic!("unexpected book {book:?}"),
+    }
+}
+
+#[test]
+fn raw_book() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::GET, "v2/book/tBTCUSD/R0")
+                .data(include_str!("fixtures/public/raw_book_trading.json")),
+        )
+        .with(
+            MockResponse::new(Method::GET, "v2/book/fUSD/R0")
+                .data(include_str!("fixtures/public/raw_book_funding.json")),
+        );
+
+    let book: RawBookResp = RawBook::builder()
+        .symbol("tBTCUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    match book {
+        RawBookResp::RawBookTradingResp(orders) => {
+            assert_eq!(orders[0].order_id, 34072546793);
+            assert_eq!(orders[1].price, 26001.);
+            assert_eq!(orders[1].amount, -0.75);
+        }
+        book => panic!("unexpected book {book:?}"),
+    }
+
+    let book: RawBookResp = RawBook::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    match book {
+        RawBookResp::RawBookFundingResp(offers) => {
+            assert_eq!(offers[1].offer_id, 2180474442);
+            assert_eq!(offers[1].period, 30);
+            assert_eq!(offers[1].rate, 0.00021);
+            assert_eq!(offers[1].amount, 2000.);
+        }
+        book => panic!("unexpected book {book:?}"),
+    }
+}
+
+#[test]
+fn candles() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::GET, "v2/candles/trade:1m:tBTCUSD/last")
+                .data(include_str!("fixtures/public/candles_last.json")),
+        )
+        .with(
+            MockResponse::new(Method::GET, "v2/candles/trade:1m:tBTCUSD/hist")
+                .params(&[("sort", "-1"), ("limit", "2")])
+                .data(include_str!("fixtures/public/candles_hist.json")),
+        );
+    let candles = AvailableCandles::TradingCandles {
+        time_frame: TimeFrame::OneMin,
+        trading_pair: Symbol::trading("BTC", "USD"),
+    };
+
+    let candle: LastCandlesResp = Candles::builder()
+        .candles(candles)
+        .section(Section::Last)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(candle.mts, 1678465320000);
+    assert_eq!(candle.open, 20097.);
+    assert_eq!(candle.close, 20094.);
+    assert_eq!(candle.volume, 0.07870586);
+
+    let candles: HistCandlesResp = Candles::builder()
+        .candles(candles)
+        .section(Section::Hist)
+        .sort(Sort::Desc)
+        .limit(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(candles.len(), 2);
+    assert_eq!(candles[1].high, 20101.);
+    assert_eq!(candles[1].low, 20095.);
+}
+
+#[test]
+fn derivative_status() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::GET, "v2/status/deriv")
+            .params(&[("keys", "tBTCF0:USTF0")])
+            .data(include_str!("fixtures/public/derivative_status.json")),
+    );
+
+    let status: DerivativesStatusResp = DerivativesStatus::builder()
+        .keys(Symbols::only(["tBTCF0:USTF0"]))
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(status.len(), 1);
+    assert_eq!(status[0].key, "tBTCF0:USTF0");
+    assert_eq!(status[0].derive_price, 29170.78);
+    assert_eq!(status[0].next_funding_evt_mts, 1691510400000);
+    assert_eq!(status[0].next_funding_step, 61);
+    assert_eq!(status[0].mark_price, 29177.27);
+    assert_eq!(status[0].open_interest, 1254.25);
+    assert_eq!(status[0].clamp_max, 0.0025);
+}
+
+#[test]
+fn derivative_status_history() {
+    let client = client(
+        "v2/status/deriv/tBTCF0:USTF0/hist",
+        include_str!("fixtures/public/derivative_status_history.json"),
+    );
+
+    let history: DerivativesStatusHistoryResp = DerivativesStatusHistory::builder()
+        .key("tBTCF0:USTF0")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(history[0].mts, 1691504226000);
+    assert_eq!(history[0].spot_price, 29188.4);
+    assert_eq!(history[0].insurrance_fund_balance, 12385.11);
+    assert_eq!(history[0].current_funding, 0.00022217);
+    assert_eq!(history[0].clamp_min, 0.5);
+}
+
+#[test]
+fn funding_statistics() {
+    let client = client(
+        "v2/funding/stats/fUSD/hist",
+        include_str!("fixtures/public/funding_statistics.json"),
+    );
+
+    let stats: FundingStatisticsResp = FundingStatistics::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(stats[0].mts, 1691503200000);
+    assert_eq!(stats[0].frr, 0.000219);
+    assert_eq!(stats[0].avg_period, 30.6);
+    assert_eq!(stats[0].funding_amount_used, 355929287.12);
+    assert_eq!(stats[0].funding_below_threshold, 6185.3);
+}
+
+#[test]
+fn leaderboards() {
+    let client = client(
+        "v2/rankings/plu:1M:tGLOBAL:USD/hist",
+        include_str!("fixtures/public/leaderboards.json"),
+    );
+
+    let rankings: HistLeaderBoardsResp = Leaderboards::builder()
+        .key(Key::Plu)
+        .time_frame(TimeFrame::OneMonth)
+        .symbol("tGLOBAL:USD")
+        .section(Section::Hist)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rankings.len(), 2);
+    assert_eq!(rankings[0].username, "satoshi");
+    assert_eq!(rankings[0].ranking, 1);
+    assert_eq!(rankings[0].value, 1538247.21);
+    assert_eq!(rankings[0].twitter_handle.as_deref(), Some("@satoshi"));
+    assert_eq!(rankings[1].twitter_handle, None);
+}
+
+#[test]
+fn liquidations() {
+    let client = client(
+        "v2/liquidations/hist",
+        include_str!("fixtures/public/liquidations.json"),
+    );
+
+    let liquidations: LiquidationsResp = Liquidations::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(liquidations.len(), 2);
+    assert_eq!(liquidations[0].pos_id, 145400868);
+    assert_eq!(liquidations[0].symbol, "tETHUSD");
+    assert_eq!(liquidations[0].amount, -0.12);
+    assert!(liquidations[0].is_match);
+    assert_eq!(liquidations[0].price_acquired, Some(736.27));
+    assert!(!liquidations[1].is_match);
+    assert_eq!(liquidations[1].price_acquired, None);
+}
+
+#[test]
+fn stats() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::GET, "v2/stats1/pos.size:1m:tBTCUSD:long/last")
+                .data(include_str!("fixtures/public/stats_last.json")),
+        )
+        .with(
+            MockResponse::new(Method::GET, "v2/stats1/pos.size:1m:tBTCUSD:long/hist")
+                .data(include_str!("fixtures/public/stats_hist.json")),
+        );
+    let key_args = KeyArgs::PosSize {
+        sym: Symbol::trading("BTC", "USD"),
+        side: Side::Long,
+    };
+
+    let stat: LastStatsResp = Stats::builder()
+        .key_args(key_args)
+        .section(Section::Last)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(stat.mts, 1691503200000);
+    assert_eq!(stat.value, 7213.9211);
+
+    let stats: HistStatsResp = Stats::builder()
+        .key_args(key_args)
+        .section(Section::Hist)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[1].value, 7210.5);
+}
+
+#[test]
+fn ticker() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::GET, "v2/ticker/tBTCUSD")
+                .data(include_str!("fixtures/public/ticker_trading.json")),
+        )
+        .with(
+            MockResponse::new(Method::GET, "v2/ticker/fUSD")
+                .data(include_str!("fixtures/public/ticker_funding.json")),
+        );
+
+    let ticker: TickerResp = Ticker::builder()
+        .symbol("tBTCUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    match ticker {
+        TickerResp::TickerTradingResp {
+            bid,
+            ask_size,
+            daily_change_relative,
+            low,
+            ..
+        } => {
+            assert_eq!(bid, 26000.);
+            assert_eq!(ask_size, 8.2);
+            assert_eq!(daily_change_relative, -0.0038);
+            assert_eq!(low, 25800.);
+        }
+        ticker => panic!("unexpected ticker {ticker:?}"),
+    }
+
+    let ticker: TickerResp = Ticker::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    match ticker {
+        TickerResp::TickerFundingResp {
+            frr,
+            bid_period,
+            ask_period,
+            frr_amount_available,
+            ..
+        } => {
+            assert_eq!(frr, 0.00016);
+            assert_eq!(bid_period, 30);
+            assert_eq!(ask_period, 2);
+            assert_eq!(frr_amount_available, 14000000.25);
+        }
+        ticker => panic!("unexpected ticker {ticker:?}"),
+    }
+}
+
+#[test]
+fn tickers() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::GET, "v2/tickers")
+            .params(&[("symbols", "tBTCUSD,fUSD")])
+            .data(include_str!("fixtures/public/tickers.json")),
+    );
+
+    let tickers: TickersResp = Tickers::builder()
+        .symbols(Symbols::only(["tBTCUSD", "fUSD"]))
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(tickers.len(), 2);
+    assert!(matches!(
+        &tickers[0],
+        tickers::TickerResp::TickersTradingResp { symbol, last_price, .. }
+            if symbol == "tBTCUSD" && *last_price == 26000.5
+    ));
+    assert!(matches!(
+        &tickers[1],
+        tickers::TickerResp::TickersFundingResp { symbol, volume, .. }
+            if symbol == "fUSD" && *volume == 250000000.
+    ));
+}
+
+#[test]
+fn tickers_history() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::GET, "v2/tickers/hist")
+            .params(&[("symbols", "ALL"), ("limit", "2")])
+            .data(include_str!("fixtures/public/tickers_history.json")),
+    );
+
+    let history: TickersHistoryResp = TickersHistory::builder()
+        .symbols(Symbols::All)
+        .limit(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[1].symbol, "tLTCUSD");
+    assert_eq!(history[1].bid, 63.1);
+    assert_eq!(history[1].ask, 63.2);
+    assert_eq!(history[1].mts, 1691503200000);
+}
+
+#[tokio::test]
+async fn trades() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::GET, "v2/trades/tBTCUSD/hist")
+                .data(include_str!("fixtures/public/trades_trading.json")),
+        )
+        .with(
+            MockResponse::new(Method::GET, "v2/trades/fUSD/hist")
+                .data(include_str!("fixtures/public/trades_funding.json")),
+        );
+
+    let trades: TradesResp = Trades::builder()
+        .symbol("tBTCUSD")
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+    match trades {
+        TradesResp::TradesTradingResp(trades) => {
+            assert_eq!(trades.len(), 2);
+            assert_eq!(trades[0].id, 1388713470);
+            assert_eq!(trades[1].amount, -0.5);
+            assert_eq!(trades[1].price, 29181.);
+        }
+        trades => panic!("unexpected trades {trades:?}"),
+    }
+
+    let trades: TradesResp = Trades::builder()
+        .symbol("fUSD")
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+    match trades {
+        TradesResp::TradesFundingResp(trades) => {
+            assert_eq!(trades[0].amount, -250.5);
+            assert_eq!(trades[0].rate, 0.0002);
+            assert_eq!(trades[0].period, 2);
+        }
+        trades => panic!("unexpected trades {trades:?}"),
+    }
+}
+
+#[test]
+fn market_average_price() {
+    let client = MockClient::new()
+        .with(
+            MockResponse::new(Method::POST, "v2/calc/trade/avg")
+                .params(&[("symbol", "tBTCUSD"), ("amount", "1.5")])
+                .data(include_str!("fixtures/public/market_average_price.json")),
+        )
+        .with(
+            MockResponse::new(Method::POST, "v2/calc/trade/avg")
+                .params(&[("symbol", "fUSD"), ("amount", "250000"), ("period", "2")])
+                .data(include_str!(
+                    "fixtures/public/market_average_price_funding.json"
+                )),
+        );
+
+    let rsp: MarketAveragePriceResp = MarketAveragePrice::builder()
+        .symbol("tBTCUSD")
+        .amount(1.5)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.price_avg, 71234.52);
+    assert_eq!(rsp.amount, 1.5);
+
+    let rsp: MarketAveragePriceResp = MarketAveragePrice::builder()
+        .symbol("fUSD")
+        .amount(250000.)
+        .period(2)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.price_avg, 0.00015);
+    assert!(!client.requests()[1].is_authenticated);
+}
+
+#[test]
+fn foreign_exchange_rate() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::POST, "v2/calc/fx")
+            .body(json!({ "ccy1": "EUR", "ccy2": "USD" }))
+            .data(include_str!("fixtures/public/foreign_exchange_rate.json")),
+    );
+
+    let rsp: ForeignExchangeRateResp = ForeignExchangeRate::builder()
+        .ccy1("EUR")
+        .ccy2("USD")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(rsp.rate, 1.0862);
+}
+
+#[test]
+fn pulse_profile() {
+    let client = client(
+        "v2/pulse/profile/Bitfinex",
+        include_str!("fixtures/public/pulse_profile.json"),
+    );
+
+    let profile: PulseProfileDetailsResp = PulseProfileDetails::builder()
+        .nickname("Bitfinex")
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(profile.nickname, "Bitfinex");
+    assert_eq!(profile.twitter_handle.as_deref(), Some("bitfinex"));
+    assert_eq!(profile.followers, 12540);
+    assert!(profile.tipping_enabled);
+}
+
+#[test]
+fn pulse_history() {
+    let client = MockClient::new().with(
+        MockResponse::new(Method::GET, "v2/pulse/hist")
+            .params(&[("limit", "1")])
+            .data(include_str!("fixtures/public/pulse_history.json")),
+    );
+
+    let pulses: PulseHistoryResp = PulseHistory::builder()
+        .limit(1)
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+    assert_eq!(pulses.len(), 1);
+    assert_eq!(pulses[0].title.as_deref(), Some("BTC weekly outlook"));
+    assert!(pulses[0].is_public);
+    assert!(!pulses[0].is_pin);
+    assert_eq!(pulses[0].tags, vec!["BTC"]);
+    assert_eq!(pulses[0].likes, 42);
+    assert_eq!(pulses[0].comments, 7);
+    assert_eq!(pulses[0].profile.as_ref().unwrap().nickname, "Bitfinex");
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use bitfinex_api::rate_limit::{RateLimit, RateLimitMode, RateLimiter};
+
+#[test]
+fn default_limits() {
+    let limiter = RateLimiter::default();
+
+    for (path, pattern, requests) in [
+        ("v2/tickers", "v2/tickers", 90),
+        ("v2/ticker/tBTCUSD", "v2/ticker/*", 90),
+        ("v2/candles/trade:1m:tBTCUSD/hist", "v2/candles/*/*", 30),
+        ("v2/book/tBTCUSD/R0", "v2/book/*/*", 90),
+        ("v2/auth/r/orders/hist", "v2/auth/r/orders/hist", 45),
+        ("v2/auth/r/orders/tBTCUSD", "v2/auth/r/orders/*", 90),
+        (
+            "v2/auth/r/funding/offers/",
+            "v2/auth/r/funding/offers/*",
+            90,
+        ),
+        ("v2/auth/w/order/submit", "v2/auth/w/order/submit", 90),
+    ] {
+        assert_eq!(
+            limiter.limit(path),
+            Some((pattern, RateLimit::per_minute(requests))),
+            "{path}"
+        );
+    }
+
+    assert_eq!(limiter.limit("v2/unknown"), None);
+    assert_eq!(limiter.acquire("/v2/unknown").unwrap(), Duration::ZERO);
+}
+
+#[test]
+fn fail_fast_and_wait() {
+    let limit = RateLimit {
+        requests: 2,
+        period: Duration::from_secs(10),
+    };
+
+    let limiter = RateLimiter::new(RateLimitMode::FailFast).with_limit("v2/ticker/*", limit);
+    assert_eq!(
+        limiter.acquire("/v2/ticker/tBTCUSD").unwrap(),
+        Duration::ZERO
+    );
+    // The symbols share the same bucket.
+    assert_eq!(
+        limiter.acquire("/v2/ticker/tETHUSD").unwrap(),
+        Duration::ZERO
+    );
+    let exceeded = limiter.acquire("/v2/ticker/tBTCUSD").unwrap_err();
+    assert_eq!(exceeded.pattern, "v2/ticker/*");
+    assert!(exceeded.retry_after > Duration::from_secs(4));
+
+    // Clones share their buckets.
+    let limiter = RateLimiter::new(RateLimitMode::Wait).with_limit("v2/ticker/*", limit);
+    let shared = limiter.clone();
+    limiter.acquire("/v2/ticker/tBTCUSD").unwrap();
+    shared.acquire("/v2/ticker/tBTCUSD").unwrap();
+    let first = limiter.acquire("/v2/ticker/tBTCUSD").unwrap();
+    let second = shared.acquire("/v2/ticker/tBTCUSD").unwrap();
+    assert!(first > Duration::from_secs(4) && first <= Duration::from_secs(5));
+    assert!(second > Duration::from_secs(9) && second <= Duration::from_secs(10));
+}
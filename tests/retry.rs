@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use bitfinex_api::{
+    api::{
+        authenticated::orders::{submit_order::SubmitOrder, types::OrderType},
+        endpoint::Endpoint,
+        public::platform_status::PlatformStatus,
+    },
+    retry::{ErrorClass, RetryPolicy},
+};
+use bytes::Bytes;
+use http::{Response, StatusCode};
+
+fn response(status: StatusCode, body: &'static str) -> Response<Bytes> {
+    Response::builder()
+        .status(status)
+        .body(Bytes::from_static(body.as_bytes()))
+        .unwrap()
+}
+
+#[test]
+fn error_classification() {
+    let classify = |status, body| ErrorClass::from_response(&response(status, body));
+
+    assert_eq!(classify(StatusCode::OK, "[1]"), None);
+    assert_eq!(
+        classify(
+            StatusCode::TOO_MANY_REQUESTS,
+            r#"{"error":"ERR_RATE_LIMIT"}"#
+        ),
+        Some(ErrorClass::RateLimit)
+    );
+    assert_eq!(
+        classify(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"["error",11010,"ratelimit: error"]"#
+        ),
+        Some(ErrorClass::RateLimit)
+    );
+    assert_eq!(
+        classify(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"["error",10114,"nonce: small"]"#
+        ),
+        Some(ErrorClass::Nonce)
+    );
+    assert_eq!(
+        classify(StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>"),
+        Some(ErrorClass::Server)
+    );
+    assert_eq!(
+        classify(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"["error",10020,"symbol: invalid"]"#
+        ),
+        Some(ErrorClass::Permanent)
+    );
+}
+
+#[test]
+fn retry_decisions() {
+    let policy = RetryPolicy::builder()
+        .max_attempts(4)
+        .initial_backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_millis(300))
+        .jitter(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        policy.delay(1, ErrorClass::Server, true),
+        Some(Duration::from_millis(100))
+    );
+    assert_eq!(
+        policy.delay(2, ErrorClass::Communication, true),
+        Some(Duration::from_millis(200))
+    );
+    assert_eq!(
+        policy.delay(3, ErrorClass::Server, true),
+        Some(Duration::from_millis(300))
+    );
+    assert_eq!(policy.delay(4, ErrorClass::Server, true), None);
+    assert_eq!(policy.delay(1, ErrorClass::Permanent, true), None);
+
+    // Writes are only retried when they are known not to have been processed.
+    assert_eq!(policy.delay(1, ErrorClass::Server, false), None);
+    assert_eq!(policy.delay(1, ErrorClass::Communication, false), None);
+    assert!(policy.delay(1, ErrorClass::Nonce, false).is_some());
+    assert!(policy.delay(1, ErrorClass::Connect, false).is_some());
+
+    let delay = RetryPolicy::default()
+        .delay(1, ErrorClass::Server, true)
+        .unwrap();
+    assert!(delay >= Duration::from_millis(250) && delay <= Duration::from_millis(500));
+
+    // Rate limit errors wait for the end of the ban.
+    assert_eq!(
+        policy.delay(1, ErrorClass::RateLimit, true),
+        Some(Duration::from_secs(60))
+    );
+    let policy = RetryPolicy::builder()
+        .rate_limit_backoff(Duration::from_secs(5))
+        .jitter(false)
+        .build()
+        .unwrap();
+    assert_eq!(
+        policy.delay(1, ErrorClass::RateLimit, false),
+        Some(Duration::from_secs(5))
+    );
+
+    assert_eq!(
+        RetryPolicy::never().delay(1, ErrorClass::Connect, true),
+        None
+    );
+
+    // A decreasing, negative or NaN backoff is rejected when building the policy.
+    for multiplier in [0.5, -2., f64::NAN, f64::INFINITY] {
+        let invalid = RetryPolicy::builder().multiplier(multiplier).build();
+        assert!(invalid.is_err(), "{multiplier}");
+    }
+}
+
+#[test]
+fn endpoint_idempotency() {
+    assert!(PlatformStatus::builder().build().unwrap().is_idempotent());
+
+    let order = |cid| {
+        let mut builder = SubmitOrder::builder();
+        builder
+            .ty(OrderType::ExchangeLimit)
+            .symbol("tBTCUSD")
+            .price(26000.)
+            .amount(0.1);
+        if let Some(cid) = cid {
+            builder.cid(cid);
+        }
+        builder.build().unwrap()
+    };
+    // Nothing looks for an order placed by a failed attempt, even with a `cid`.
+    assert!(!order(None).is_idempotent());
+    assert!(!order(Some(42)).is_idempotent());
+}
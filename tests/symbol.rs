@@ -0,0 +1,80 @@
+use bitfinex_api::api::common::{Symbol, SymbolError, Symbols};
+
+#[test]
+fn symbol_round_trip() {
+    for symbol in [
+        "tBTCUSD",
+        "tBTCF0:USTF0",
+        "t1INCH:USD",
+        "tTESTBTC:TESTUSD",
+        "fUSD",
+        "fUST",
+    ] {
+        assert_eq!(Symbol::parse(symbol).unwrap().to_string(), symbol);
+    }
+
+    assert_eq!(Symbol::parse("tBTCUSD"), Ok(Symbol::trading("BTC", "USD")));
+    assert_eq!(
+        Symbol::parse("tBTCF0:USTF0"),
+        Ok(Symbol::trading("BTCF0", "USTF0"))
+    );
+    assert_eq!(Symbol::parse("fUSD"), Ok(Symbol::funding("USD")));
+    assert_eq!(Symbol::trading("ETH", "USD").to_string(), "tETHUSD");
+    assert_eq!(Symbol::trading("AVAX", "USD").to_string(), "tAVAX:USD");
+    assert_eq!(Symbol::funding("USD").to_string(), "fUSD");
+}
+
+#[test]
+fn invalid_symbols() {
+    for symbol in [
+        "", "t", "f", "BTCUSD", "tBTCUS", "tBTC:USD", "tBTC:", "tbtcusd", "fUS-D",
+    ] {
+        assert_eq!(
+            Symbol::parse(symbol),
+            Err(SymbolError {
+                symbol: symbol.to_string()
+            })
+        );
+    }
+
+    // Strings that can't be parsed are kept as is.
+    let symbol = Symbol::from("tBTC:USD");
+    assert_eq!(symbol, Symbol::Raw("tBTC:USD"));
+    assert_eq!(symbol.to_string(), "tBTC:USD");
+    assert!(symbol.is_trading());
+}
+
+#[test]
+fn symbol_kinds() {
+    assert!(Symbol::from("tBTCUSD").is_trading());
+    assert!(!Symbol::from("tBTCUSD").is_funding());
+    assert!(!Symbol::from("tBTCUSD").is_derivative());
+
+    assert!(Symbol::from("fUSD").is_funding());
+    assert!(!Symbol::from("fUSD").is_trading());
+
+    assert!(Symbol::from("tBTCF0:USTF0").is_derivative());
+    assert!(Symbol::from("tBTCF0:USTF0").is_trading());
+    assert!(!Symbol::from("tTESTBTC:TESTUSD").is_derivative());
+}
+
+#[test]
+fn symbols_query_string() {
+    assert_eq!(Symbols::All.as_query_string(), "ALL");
+    assert_eq!(
+        Symbols::only(["tBTCUSD", "fUSD"]).as_query_string(),
+        "tBTCUSD,fUSD"
+    );
+    assert_eq!(
+        Symbols::Only(vec![
+            Symbol::trading("BTCF0", "USTF0"),
+            Symbol::funding("UST")
+        ])
+        .as_query_string(),
+        "tBTCF0:USTF0,fUST"
+    );
+    assert_eq!(
+        serde_json::to_string(&Symbol::trading("BTC", "USD")).unwrap(),
+        r#""tBTCUSD""#
+    );
+}
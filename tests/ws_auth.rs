@@ -0,0 +1,104 @@
+mod common;
+
+use bitfinex_api::{
+    api::authenticated::wallets::WalletType,
+    auth::Auth,
+    ws::{account::AccountEvent, client::BitfinexWs, events::WsEvent},
+};
+use common::serve;
+use serde_json::Value;
+
+#[tokio::test]
+async fn account_events() {
+    let (url, server) = serve(vec![vec![
+        r#"{"event":"auth","status":"OK","chanId":0,"userId":1234,"auth_id":"a26236f1","caps":{}}"#,
+        r#"[0,"os",[[1185815098,null,1541424883520,"tBTCUSD",1541424883000,1541424883000,0.001,0.001,"EXCHANGE LIMIT",null,null,null,0,"ACTIVE",null,null,6400,0,0,0,null,null,null,0,0,null,null,null,"API>BFX",null,null,null]]]"#,
+        r#"[0,"ws",[["exchange","BTC",1.5,0,null,null,null]]]"#,
+        r#"[0,"te",[402088407,"tBTCUSD",1574963975602,1185815098,0.001,6400,"EXCHANGE LIMIT",6400,1,null,null,1541424883520]]"#,
+        r#"[0,"ps",[["tETHUSD","ACTIVE",-0.2,167.01,0,0,null,null,null,null,null,142661142,null,null,null,0,null,0,null,{"reason":"TRADE"}]]]"#,
+        r#"[0,"n",[1575282446099,"fon-req",null,null,[41238905,"fUSD",1575282446000,1575282446000,-50,-50,"LIMIT",null,null,0,"ACTIVE",null,null,null,0.002,2,false,false,null,false,null],null,"SUCCESS","Submitting funding bid of 50.0 USD at 0.2000 for 2 days."]]"#,
+        r#"[0,"hb"]"#,
+    ]])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.authenticate(&Auth::new("api-key".into(), "secret-key".into()))
+        .unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Authenticated { user_id: 1234 }
+    ));
+
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Account(AccountEvent::OrdersSnapshot(orders)) => {
+            assert_eq!(orders.len(), 1);
+            assert_eq!(orders[0].id, 1185815098);
+            assert_eq!(orders[0].symbol, "tBTCUSD");
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Account(AccountEvent::WalletsSnapshot(wallets)) => {
+            assert_eq!(wallets[0].ty, WalletType::Exchange);
+            assert_eq!(wallets[0].available_balance, None);
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Account(AccountEvent::TradeExecuted(trade)) => {
+            assert_eq!(trade.order_id, 1185815098);
+            assert!(trade.maker);
+            assert_eq!(trade.fee, None);
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Account(AccountEvent::PositionsSnapshot(positions)) => {
+            assert_eq!(positions[0].position_id, 142661142);
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Account(AccountEvent::Notification(notification)) => {
+            assert_eq!(notification.ty, "fon-req");
+            assert_eq!(notification.status, "SUCCESS");
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Heartbeat { chan_id: 0 }
+    ));
+
+    let received = server.await.unwrap();
+    let auth = serde_json::from_str::<Value>(&received[0]).unwrap();
+    assert_eq!(auth["event"], "auth");
+    assert_eq!(auth["apiKey"], "api-key");
+    assert_eq!(
+        auth["authPayload"],
+        format!("AUTH{}", auth["authNonce"].as_u64().unwrap())
+    );
+}
+
+#[tokio::test]
+async fn auth_failure() {
+    let (url, _) = serve(vec![vec![
+        r#"{"event":"auth","status":"FAILED","chanId":0,"code":10100,"msg":"apikey: invalid"}"#,
+    ]])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.authenticate(&Auth::new("api-key".into(), "secret-key".into()))
+        .unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::AuthFailed {
+            code: Some(10100),
+            ..
+        }
+    ));
+}
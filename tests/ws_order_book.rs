@@ -0,0 +1,167 @@
+mod common;
+
+use bitfinex_api::{
+    api::public::book::book::Precision,
+    ws::{
+        client::BitfinexWs,
+        error::WsError,
+        events::WsEvent,
+        order_book::OrderBook,
+        subscription::{ConfFlag, Frequency, Subscription},
+    },
+};
+
+use common::serve;
+
+#[tokio::test]
+async fn aggregated_book_with_checksum() {
+    let (url, received) = serve(vec![
+        vec![r#"{"event":"conf","status":"OK","flags":131072}"#],
+        vec![
+            r#"{"event":"subscribed","channel":"book","chanId":3,"symbol":"tBTCUSD","prec":"P0","freq":"F0","len":"25","pair":"BTCUSD"}"#,
+            r#"[3,[[26000,2,1.5],[25999,1,0.25],[26001,1,-0.5],[26002,3,-2]]]"#,
+            r#"[3,[25999,0,1]]"#,
+            r#"[3,"cs",-56266192]"#,
+        ],
+    ])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.configure(&[ConfFlag::Checksum]).unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Conf {
+            flags: Some(131072),
+            ..
+        }
+    ));
+
+    ws.subscribe(&Subscription::book(
+        "tBTCUSD",
+        Precision::P0,
+        Frequency::F0,
+        None,
+    ))
+    .unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    let mut book = OrderBook::new(3);
+    for _ in 0..3 {
+        let event = ws.next_event().await.unwrap().unwrap();
+        book.handle_event(&event).unwrap();
+    }
+
+    assert_eq!(book.depth(), (1, 2));
+    assert_eq!(book.best_bid().unwrap().price, 26000.);
+    assert_eq!(book.best_ask().unwrap().price, 26001.);
+    assert_eq!(book.spread(), Some(1.));
+    assert_eq!(book.ask_volume(26002.), 2.5);
+    assert_eq!(book.checksum(), -56266192);
+
+    let received = received.await.unwrap();
+    assert_eq!(received[0], r#"{"event":"conf","flags":131072}"#);
+}
+
+#[tokio::test]
+async fn aggregated_book_with_bulk_updates() {
+    let (url, received) = serve(vec![
+        vec![r#"{"event":"conf","status":"OK","flags":536870912}"#],
+        vec![
+            r#"{"event":"subscribed","channel":"book","chanId":5,"symbol":"tBTCUSD","prec":"P0","freq":"F0","len":"25","pair":"BTCUSD"}"#,
+            r#"[5,[[26000,2,1.5],[25999,1,0.25],[26001,1,-0.5],[26002,3,-2]]]"#,
+            r#"[5,[[25999,0,1],[26001,2,-1]]]"#,
+            r#"[5,[[25998,1,3]]]"#,
+        ],
+    ])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.configure(&[ConfFlag::BulkUpdates]).unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.subscribe(&Subscription::book(
+        "tBTCUSD",
+        Precision::P0,
+        Frequency::F0,
+        None,
+    ))
+    .unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    let mut book = OrderBook::new(5);
+    let snapshot = ws.next_event().await.unwrap().unwrap();
+    assert!(matches!(snapshot, WsEvent::BookSnapshot { chan_id: 5, .. }));
+    book.handle_event(&snapshot).unwrap();
+
+    // The updates sent after the snapshot are applied, not treated as a new snapshot.
+    let updates = ws.next_event().await.unwrap().unwrap();
+    assert!(matches!(
+        &updates,
+        WsEvent::BookUpdates { chan_id: 5, entries } if entries.len() == 2
+    ));
+    book.handle_event(&updates).unwrap();
+    book.handle_event(&ws.next_event().await.unwrap().unwrap())
+        .unwrap();
+
+    assert_eq!(book.depth(), (2, 2));
+    assert_eq!(book.bids()[1].price, 25998.);
+    assert_eq!(book.best_ask().unwrap().count, 2);
+    assert_eq!(book.ask_volume(26002.), 3.);
+
+    let received = received.await.unwrap();
+    assert_eq!(received[0], r#"{"event":"conf","flags":536870912}"#);
+}
+
+#[tokio::test]
+async fn raw_book_checksum_mismatch() {
+    let (url, received) = serve(vec![vec![
+        r#"{"event":"subscribed","channel":"book","chanId":4,"symbol":"tBTCUSD","prec":"R0","len":"25","pair":"BTCUSD"}"#,
+        r#"[4,[[101,26000,1.5],[102,25999,0.25],[103,26001,-0.5],[104,26002,-2]]]"#,
+        r#"[4,"cs",-581915446]"#,
+        r#"[4,[102,0,1]]"#,
+        r#"[4,"cs",-581915446]"#,
+    ]])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.subscribe(&Subscription::raw_book("tBTCUSD", None))
+        .unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Subscribed {
+            chan_id: 4,
+            subscription: Subscription::RawBook { .. },
+        }
+    ));
+
+    let mut book = OrderBook::new(4);
+    for _ in 0..2 {
+        let event = ws.next_event().await.unwrap().unwrap();
+        book.handle_event(&event).unwrap();
+    }
+    assert_eq!(book.bids()[1].id, Some(102));
+
+    let event = ws.next_event().await.unwrap().unwrap();
+    book.handle_event(&event).unwrap();
+    assert_eq!(book.depth(), (1, 2));
+
+    let event = ws.next_event().await.unwrap().unwrap();
+    assert!(matches!(
+        book.handle_event(&event),
+        Err(WsError::ChecksumMismatch {
+            chan_id: 4,
+            expected: -581915446,
+            ..
+        })
+    ));
+    assert_eq!(book.depth(), (0, 0));
+
+    let received = received.await.unwrap();
+    assert!(received[0].contains(r#""prec":"R0""#));
+}
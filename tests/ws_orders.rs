@@ -0,0 +1,143 @@
+mod common;
+
+use std::time::Duration;
+
+use bitfinex_api::{
+    api::authenticated::orders::{
+        cancel_order::CancelOrder, submit_order::SubmitOrder, types::OrderType,
+    },
+    ws::{client::BitfinexWs, orders::OrderOp},
+};
+use common::serve;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn order_requests_resolve_with_their_notification() {
+    let (url, server) = serve(vec![
+        vec![
+            r#"[0,"n",[1575289447641,"on-req",null,null,[1185815100,null,42,"tBTCUSD",1575289447641,1575289447641,0.1,0.1,"EXCHANGE LIMIT",null,null,null,0,"ACTIVE",null,null,6400,0,0,0,null,null,null,0,0,null,null,null,"API>BFX",null,null,null],null,"SUCCESS","Submitting exchange limit buy order for 0.1 BTC."]]"#,
+        ],
+        vec![
+            r#"[0,"n",[1575289447700,"oc-req",null,null,[1185815100,null,42,"tBTCUSD",1575289447641,1575289447700,0.1,0.1,"EXCHANGE LIMIT",null,null,null,0,"CANCELED",null,null,6400,0,0,0,null,null,null,0,0,null,null,null,"API>BFX",null,null,null],null,"SUCCESS","Submitted for cancellation."]]"#,
+        ],
+        vec![
+            r#"[0,"n",[1575289447800,"ox_multi-req",null,null,null,null,"SUCCESS","Submitting 1 order operations."]]"#,
+        ],
+    ])
+    .await;
+
+    let ws = BitfinexWs::connect_to(&url).await.unwrap();
+
+    let order = SubmitOrder::builder()
+        .ty(OrderType::ExchangeLimit)
+        .symbol("tBTCUSD")
+        .amount(0.1)
+        .price(6400.)
+        .cid(42)
+        .build()
+        .unwrap();
+    let notification = ws.submit_order(&order).unwrap().await.unwrap();
+    assert_eq!(notification.status, "SUCCESS");
+    assert_eq!(notification.order().unwrap().id, 1185815100);
+
+    let cancel = CancelOrder::builder().id(1185815100).build().unwrap();
+    let notification = ws.cancel_order(&cancel).unwrap().await.unwrap();
+    assert_eq!(notification.order().unwrap().status, "CANCELED");
+
+    let order = SubmitOrder::builder()
+        .ty(OrderType::ExchangeLimit)
+        .symbol("tBTCUSD")
+        .amount(0.1)
+        .price(6400.)
+        .build()
+        .unwrap();
+    let notification = ws
+        .order_multi(&[OrderOp::Submit(&order)])
+        .unwrap()
+        .await
+        .unwrap();
+    assert_eq!(notification.ty, "ox_multi-req");
+
+    let received = server
+        .await
+        .unwrap()
+        .iter()
+        .map(|msg| serde_json::from_str::<Value>(msg).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        received[0],
+        json!([0, "on", null, {
+            "type": "EXCHANGE LIMIT",
+            "symbol": "tBTCUSD",
+            "amount": "0.1",
+            "price": "6400",
+            "cid": 42,
+        }])
+    );
+    assert_eq!(received[1], json!([0, "oc", null, { "id": 1185815100 }]));
+    assert_eq!(received[2][1], "ox_multi");
+    assert_eq!(received[2][3][0][0], "on");
+    assert!(received[2][3][0][1]["cid"].is_u64());
+}
+
+#[tokio::test]
+async fn notifications_only_resolve_the_request_with_their_id() {
+    let (url, _) = serve(vec![
+        vec![],
+        vec![
+            r#"[0,"n",[1575289447641,"on-req",null,null,[null,null,null,"tBTCUSD",null,null,0.1,0.1,"EXCHANGE LIMIT",null,null,null,null,null,null,null,6400,0,0,0,null,null,null,0,null,null,null,null,null,null,null,null],null,"ERROR","Invalid order: not enough exchange balance."]]"#,
+            r#"[0,"n",[1575289447642,"on-req",null,null,[1185815101,null,2,"tBTCUSD",1575289447642,1575289447642,0.2,0.2,"EXCHANGE LIMIT",null,null,null,0,"ACTIVE",null,null,6400,0,0,0,null,null,null,0,0,null,null,null,"API>BFX",null,null,null],null,"SUCCESS","Submitting exchange limit buy order for 0.2 BTC."]]"#,
+            r#"[0,"n",[1575289447643,"on-req",null,null,[1185815100,null,1,"tBTCUSD",1575289447643,1575289447643,0.1,0.1,"EXCHANGE LIMIT",null,null,null,0,"ACTIVE",null,null,6400,0,0,0,null,null,null,0,0,null,null,null,"API>BFX",null,null,null],null,"SUCCESS","Submitting exchange limit buy order for 0.1 BTC."]]"#,
+        ],
+    ])
+    .await;
+
+    let ws = BitfinexWs::connect_to(&url).await.unwrap();
+
+    let order = |cid, amount| {
+        SubmitOrder::builder()
+            .ty(OrderType::ExchangeLimit)
+            .symbol("tBTCUSD")
+            .amount(amount)
+            .price(6400.)
+            .cid(cid)
+            .build()
+            .unwrap()
+    };
+    let first = ws.submit_order(&order(1, 0.1)).unwrap();
+    let second = ws.submit_order(&order(2, 0.2)).unwrap();
+
+    let notification = first.await.unwrap();
+    assert_eq!(notification.status, "SUCCESS");
+    assert_eq!(notification.order().unwrap().cid, 1);
+
+    let notification = second.await.unwrap();
+    assert_eq!(notification.order().unwrap().cid, 2);
+}
+
+#[tokio::test]
+async fn rejections_without_an_order_resolve_the_oldest_request() {
+    let (url, _) = serve(vec![vec![
+        r#"[0,"n",[1575289447641,"on-req",null,null,null,null,"ERROR","Invalid order: minimum size for BTC/USD is 0.0001"]]"#,
+    ]])
+    .await;
+
+    let ws = BitfinexWs::connect_to(&url).await.unwrap();
+
+    let order = SubmitOrder::builder()
+        .ty(OrderType::ExchangeLimit)
+        .symbol("tBTCUSD")
+        .amount(0.00001)
+        .price(6400.)
+        .cid(1)
+        .build()
+        .unwrap();
+    let rejected = ws.submit_order(&order).unwrap();
+
+    let notification = tokio::time::timeout(Duration::from_secs(5), rejected)
+        .await
+        .expect("the rejected order never resolved")
+        .unwrap();
+    assert_eq!(notification.status, "ERROR");
+    assert!(notification.order().is_none());
+}
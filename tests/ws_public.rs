@@ -0,0 +1,175 @@
+mod common;
+
+use bitfinex_api::{
+    api::{
+        common::{Symbol, TimeFrame},
+        public::{book::book::Precision, candles::AvailableCandles, ticker::TickerResp},
+    },
+    ws::{
+        client::BitfinexWs,
+        events::{BookEntry, TradeEventKind, WsEvent},
+        subscription::{Frequency, Subscription},
+    },
+};
+
+use common::serve;
+use serde_json::{json, Value};
+
+#[tokio::test]
+async fn ticker_and_book_channels() {
+    let (url, _) = serve(vec![
+        vec![
+            r#"{"event":"subscribed","channel":"ticker","chanId":1,"symbol":"tBTCUSD","pair":"BTCUSD"}"#,
+            r#"[1,[26000,10.5,26001,8.2,-100,-0.0038,26000.5,1200.3,26500,25800]]"#,
+            r#"[1,"hb"]"#,
+        ],
+        vec![
+            r#"{"event":"subscribed","channel":"book","chanId":2,"symbol":"tBTCUSD","prec":"P0","freq":"F0","len":"25","pair":"BTCUSD"}"#,
+            r#"[2,[[26000,2,1.5],[26001,1,-0.5]]]"#,
+            r#"[2,[26000,0,1]]"#,
+        ],
+        vec![r#"{"event":"unsubscribed","status":"OK","chanId":1}"#],
+    ])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Info {
+            version: Some(2),
+            platform_status: Some(1),
+            ..
+        }
+    ));
+
+    ws.subscribe(&Subscription::ticker("tBTCUSD")).unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Subscribed { chan_id: 1, .. }
+    ));
+    assert_eq!(ws.subscription(1), Some(Subscription::ticker("tBTCUSD")));
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Ticker {
+            chan_id: 1,
+            ticker: TickerResp::TickerTradingResp { bid, ask, .. },
+        } => assert_eq!((bid, ask), (26000., 26001.)),
+        event => panic!("unexpected event {event:?}"),
+    }
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Heartbeat { chan_id: 1 }
+    ));
+
+    let book = Subscription::book("tBTCUSD", Precision::P0, Frequency::F0, None);
+    ws.subscribe(&book).unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Subscribed { chan_id: 2, .. }
+    ));
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::BookSnapshot { chan_id: 2, .. }
+    ));
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::BookUpdate {
+            chan_id: 2,
+            entry: BookEntry::Trading(level),
+        } => assert_eq!((level.price, level.count), (26000., 0)),
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    ws.unsubscribe(1).unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Unsubscribed { chan_id: 1 }
+    ));
+    assert_eq!(ws.subscription(1), None);
+}
+
+#[tokio::test]
+async fn trades_channel_and_errors() {
+    let (url, _) = serve(vec![
+        vec![
+            r#"{"event":"subscribed","channel":"trades","chanId":7,"symbol":"tBTCUSD","pair":"BTCUSD"}"#,
+            r#"[7,[[401597395,1574694478808,0.005,7245.3]]]"#,
+            r#"[7,"te",[401597396,1574694478900,-0.1,7245.1]]"#,
+        ],
+        vec![r#"{"event":"error","msg":"symbol: invalid","code":10300}"#],
+    ])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    ws.subscribe(&Subscription::trades("tBTCUSD")).unwrap();
+    ws.next_event().await.unwrap().unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::TradesSnapshot { chan_id: 7, .. }
+    ));
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Trade {
+            chan_id: 7,
+            kind: TradeEventKind::Executed,
+            ..
+        }
+    ));
+
+    ws.subscribe(&Subscription::trades("tFOOBAR")).unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Error { code: 10300, .. }
+    ));
+}
+
+#[tokio::test]
+async fn candles_channel() {
+    let (url, server) = serve(vec![vec![
+        r#"{"event":"subscribed","channel":"candles","chanId":343351,"key":"trade:1m:tBTCUSD"}"#,
+        r#"[343351,[[1574698260000,7379.78,7379.8,7379.8,7379.78,0.5],[1574698200000,7380,7379.78,7380,7379.7,1.2]]]"#,
+        r#"[343351,[1574698320000,7379.8,7381.1,7381.2,7379.8,0.3]]"#,
+    ]])
+    .await;
+
+    let mut ws = BitfinexWs::connect_to(&url).await.unwrap();
+    ws.next_event().await.unwrap().unwrap();
+
+    let candles = Subscription::candles(AvailableCandles::TradingCandles {
+        time_frame: TimeFrame::OneMin,
+        trading_pair: Symbol::trading("BTC", "USD"),
+    });
+    ws.subscribe(&candles).unwrap();
+    assert!(matches!(
+        ws.next_event().await.unwrap().unwrap(),
+        WsEvent::Subscribed {
+            chan_id: 343351,
+            ..
+        }
+    ));
+    assert_eq!(ws.subscription(343351), Some(candles));
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::CandlesSnapshot {
+            chan_id: 343351,
+            candles,
+        } => {
+            assert_eq!(candles.len(), 2);
+            assert_eq!(candles[1].volume, 1.2);
+        }
+        event => panic!("unexpected event {event:?}"),
+    }
+    match ws.next_event().await.unwrap().unwrap() {
+        WsEvent::Candle {
+            chan_id: 343351,
+            candle,
+        } => assert_eq!((candle.mts, candle.close), (1574698320000, 7381.1)),
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    let received = server.await.unwrap();
+    assert_eq!(
+        serde_json::from_str::<Value>(&received[0]).unwrap(),
+        json!({ "event": "subscribe", "channel": "candles", "key": "trade:1m:tBTCUSD" })
+    );
+}